@@ -1,4 +1,6 @@
 use crate::models::*;
+use crate::netlink::*;
+use crate::netns::*;
 use crate::parser::*;
 use crate::theme::Theme;
 use crate::theme::THEMES;
@@ -9,35 +11,112 @@ use crossterm::event::{self, Event, KeyCode};
 use ratatui::widgets::ScrollbarState;
 use ratatui::DefaultTerminal;
 use ratatui::Frame;
-use std::collections::HashMap;
 use std::collections::VecDeque;
+use std::collections::{HashMap, HashSet};
+use std::fs::File;
+use std::net::IpAddr;
+use std::path::Path;
 use std::result::Result::Ok;
 use std::sync::{Arc, Mutex};
 use std::time::{Duration, Instant};
 
+const PROCESS_INDEX_INTERVAL: Duration = Duration::from_secs(2);
+// How long a vanished interface keeps its row and history, e.g. across a VPN reconnect.
+const INTERFACE_GRACE: Duration = Duration::from_secs(60);
+// Runtimes create the veth pair before moving one end into the container, so unresolved veths
+// are retried for a while instead of only when the interface set changes.
+const VETH_RETRY: Duration = Duration::from_secs(10);
+// A neighbour whose MAC changed stays flagged this long, so a one-tick flip isn't missed.
+const MAC_CHANGE_HOLD: Duration = Duration::from_secs(60);
+// New connections are highlighted in the sockets table this long, and closed ones linger there
+// greyed out, so changes are noticeable across a couple of ticks.
+const CONNECTION_HIGHLIGHT: Duration = Duration::from_secs(5);
+// Closed connections kept for the Recent panel, oldest dropped first.
+const RECENT_CAPACITY: usize = 500;
+
 pub struct App {
     pub change_theme: bool,
     pub current_theme: Theme,
     pub theme_index: Option<usize>,
-    pub update_avg: bool,
+    pub rx_peak_speed: HashMap<String, f64>,
+    pub tx_peak_speed: HashMap<String, f64>,
+    pub rx_avg_speed: HashMap<String, f64>,
+    pub tx_avg_speed: HashMap<String, f64>,
     pub main_tab_focus: bool,
-    pub interface_speeds: HashMap<String, (f64, f64)>,
+    pub interface_speeds: HashMap<String, (Option<f64>, Option<f64>)>,
+    pub detected_speeds: HashMap<String, LinkSpeed>,
     pub edit_rx_mode: bool,
     pub edit_tx_mode: bool,
     pub speed_input: String,
     pub editing_interface: Option<String>,
-    pub hostname_cache_arc: Arc<Mutex<HashMap<[u8; 4], String>>>,
+    pub hostname_cache_arc: Arc<Mutex<HashMap<IpAddr, String>>>,
+    pub process_cache_arc: Arc<Mutex<HashMap<u64, ProcessInfo>>>,
     pub show_help: bool,
     pub enter_tick_active: bool,
     pub tick_rate: Duration,
     pub tick_value: String,
     pub mode: Mode,
-    pub selected_index: Option<usize>,
+    pub locked: Option<LockedSocket>,
+    pub connections: HashMap<ConnectionKey, TrackedConnection>,
+    // Closed connections, newest first.
+    pub recent: VecDeque<ClosedConnection>,
+    pub lifecycle_counts: LifecycleCounts,
+    pub sockets_read_at: Option<Instant>,
     pub selected_interface: InterfaceSelected,
     pub prev_stats: Option<Vec<NetworkStats>>,
+    pub gone_interfaces: HashMap<String, (NetworkStats, Instant)>,
     pub tcp_stats: Option<Vec<TcpStats>>,
-    pub rx_data: HashMap<String, Vec<(f64, f64)>>,
-    pub tx_data: HashMap<String, Vec<(f64, f64)>>,
+    pub udp_stats: Option<Vec<UdpStats>>,
+    // When each TCP flow was last sampled with its tcp_info bytes_received/bytes_acked, and the
+    // RX/TX rates derived from consecutive samples.
+    pub flow_bytes: HashMap<SocketKey, (f64, u64, u64)>,
+    pub flow_rates: HashMap<SocketKey, (f64, f64)>,
+    pub process_traffic: HashMap<u32, ProcessTraffic>,
+    // The Processes cursor follows a PID, since rows are re-sorted by rate every tick.
+    pub selected_process: Option<u32>,
+    pub tcp_details: Option<TcpDetails>,
+    pub socket_view: SocketView,
+    pub socket_sort: SocketSort,
+    pub panel: Panel,
+    pub proto_stats: Option<(f64, ProtoStats)>,
+    pub sockstat: Option<SockStat>,
+    pub wireless_stats: HashMap<String, WirelessStats>,
+    pub routes: Vec<Route>,
+    pub neighbors: Vec<Neighbor>,
+    pub mac_changes: HashMap<(IpAddr, String), (String, Instant)>,
+    pub conntrack: Vec<ConntrackEntry>,
+    pub conntrack_usage: Option<(u64, u64)>,
+    pub conntrack_sort: ConntrackSort,
+    pub conntrack_backend: Backend,
+    pub local_addrs: HashSet<IpAddr>,
+    pub parents: HashMap<String, String>,
+    pub bonds: HashMap<String, BondInfo>,
+    pub collapsed: HashSet<String>,
+    pub veth_peers: HashMap<String, VethPeer>,
+    pub veth_links: Vec<VethLink>,
+    pub veth_resolved_at: Option<Instant>,
+    pub veth_result: Arc<Mutex<Option<VethResolution>>>,
+    pub veth_resolving: bool,
+    pub netns: NetNs,
+    pub home_netns: Option<(File, NetNs)>,
+    pub netns_list: Vec<NetNs>,
+    pub netns_error: Option<String>,
+    pub signal_history: HashMap<String, Vec<f64>>,
+    pub prev_proto_stats: Option<(f64, ProtoStats)>,
+    pub cpu_net_stats: Option<(f64, Vec<CpuNetStats>)>,
+    pub prev_cpu_net_stats: Option<(f64, Vec<CpuNetStats>)>,
+    pub irq_cpus: Vec<usize>,
+    pub irq_stats: Option<(f64, Vec<IrqStats>)>,
+    pub prev_irq_stats: Option<(f64, Vec<IrqStats>)>,
+    pub irq_affinity: HashMap<u32, String>,
+    pub net_queues: Option<(f64, String, Vec<NetQueue>)>,
+    pub prev_net_queues: Option<(f64, String, Vec<NetQueue>)>,
+    pub socket_backend: Backend,
+    pub link_backend: Backend,
+    pub rx_data: HashMap<String, Vec<Sample>>,
+    // Per-second rates of each interface's RX and TX counters, in `counters()` order.
+    pub counter_rates: HashMap<String, (Vec<f64>, Vec<f64>)>,
+    pub tx_data: HashMap<String, Vec<Sample>>,
     pub start_time: Instant,
     pub window: [f64; 2],
     pub raw_bytes: bool,
@@ -49,29 +128,39 @@ pub struct App {
     pub tcp_vertical_scroll_state: ScrollbarState,
     pub tcp_vertical_scroll: usize,
     pub focus: Focus,
-    pub total_rx_history: Vec<u64>,
-    pub total_tx_history: Vec<u64>,
+    pub total_rx_history: Vec<Option<u64>>,
+    pub total_tx_history: Vec<Option<u64>>,
     pub history_capacity: usize,
 }
 
 impl Default for App {
     fn default() -> Self {
+        let home_netns = open_home_netns();
         Self {
             current_theme: get_theme(),
             change_theme: false,
             theme_index: None,
-            update_avg: false,
-            selected_index: None,
+            rx_peak_speed: HashMap::new(),
+            tx_peak_speed: HashMap::new(),
+            rx_avg_speed: HashMap::new(),
+            tx_avg_speed: HashMap::new(),
+            locked: None,
+            connections: HashMap::new(),
+            recent: VecDeque::new(),
+            lifecycle_counts: LifecycleCounts::default(),
+            sockets_read_at: None,
             total_rx_history: Vec::new(),
             total_tx_history: Vec::new(),
             history_capacity: 120,
             main_tab_focus: true,
             interface_speeds: get_interface_speed(),
+            detected_speeds: HashMap::new(),
             edit_tx_mode: false,
             edit_rx_mode: false,
             speed_input: String::new(),
             editing_interface: None,
             hostname_cache_arc: Arc::new(Mutex::new(HashMap::new())),
+            process_cache_arc: Arc::new(Mutex::new(HashMap::new())),
             focus: Focus::Interfaces,
             show_help: false,
             tick_value: String::new(),
@@ -80,8 +169,62 @@ impl Default for App {
             mode: Mode::Normal,
             selected_interface: InterfaceSelected::All,
             prev_stats: None,
+            gone_interfaces: HashMap::new(),
             tcp_stats: None,
+            udp_stats: None,
+            flow_bytes: HashMap::new(),
+            flow_rates: HashMap::new(),
+            process_traffic: HashMap::new(),
+            selected_process: None,
+            tcp_details: None,
+            socket_view: SocketView::default(),
+            socket_sort: SocketSort::default(),
+            panel: Panel::default(),
+            proto_stats: None,
+            sockstat: None,
+            wireless_stats: HashMap::new(),
+            routes: Vec::new(),
+            neighbors: Vec::new(),
+            mac_changes: HashMap::new(),
+            conntrack: Vec::new(),
+            conntrack_usage: None,
+            conntrack_sort: ConntrackSort::default(),
+            conntrack_backend: Backend::Netlink,
+            local_addrs: HashSet::new(),
+            parents: HashMap::new(),
+            bonds: HashMap::new(),
+            collapsed: HashSet::new(),
+            veth_peers: HashMap::new(),
+            veth_links: Vec::new(),
+            veth_resolved_at: None,
+            veth_result: Arc::new(Mutex::new(None)),
+            veth_resolving: false,
+            netns: home_netns
+                .as_ref()
+                .map(|(_, ns)| ns.clone())
+                .unwrap_or(NetNs {
+                    name: "initial".to_string(),
+                    path: "/proc/self/ns/net".into(),
+                    inode: 0,
+                    processes: 0,
+                }),
+            home_netns,
+            netns_list: Vec::new(),
+            netns_error: None,
+            signal_history: HashMap::new(),
+            prev_proto_stats: None,
+            cpu_net_stats: None,
+            prev_cpu_net_stats: None,
+            irq_cpus: Vec::new(),
+            irq_stats: None,
+            prev_irq_stats: None,
+            irq_affinity: HashMap::new(),
+            net_queues: None,
+            prev_net_queues: None,
+            socket_backend: Backend::detect_sockets(),
+            link_backend: Backend::detect_links(),
             rx_data: HashMap::new(),
+            counter_rates: HashMap::new(),
             tx_data: HashMap::new(),
             start_time: Instant::now(),
             window: [0.0, 60.0],
@@ -109,10 +252,255 @@ pub enum Mode {
     Normal,
     SelectingInterface { filter: String, index: usize },
     FilterLocalAddress { filter: String, index: usize },
+    FilterNeighbors { filter: String, index: usize },
+    FilterConntrack { filter: String, index: usize },
     SelectingTheme { filter: String, index: usize },
+    SelectingNetns { filter: String, index: usize },
 }
 
-#[derive(Clone, Debug)]
+// Which sockets the bottom table lists, cycled with `u`.
+#[derive(Default, Clone, Copy, Debug, PartialEq)]
+pub enum SocketView {
+    #[default]
+    Tcp,
+    Udp,
+    All,
+}
+
+impl SocketView {
+    pub fn next(&self) -> Self {
+        match self {
+            SocketView::Tcp => SocketView::Udp,
+            SocketView::Udp => SocketView::All,
+            SocketView::All => SocketView::Tcp,
+        }
+    }
+
+    pub fn title(&self) -> &'static str {
+        match self {
+            SocketView::Tcp => "Tcp Connections",
+            SocketView::Udp => "Udp Sockets",
+            SocketView::All => "Tcp + Udp Sockets",
+        }
+    }
+}
+
+// The connection locked with Enter in the sockets filter. It is followed by its key rather than
+// its row, and once the socket is gone its last snapshot stays on screen, marked closed.
+pub struct LockedSocket {
+    pub key: SocketKey,
+    pub last: SocketSnapshot,
+    pub closed: bool,
+}
+
+impl LockedSocket {
+    pub fn new(key: SocketKey, tcp: &[TcpStats], udp: &[UdpStats]) -> Option<Self> {
+        Some(LockedSocket {
+            key,
+            last: SocketSnapshot::find(&key, tcp, udp)?,
+            closed: false,
+        })
+    }
+
+    // Follows the socket into a new tick, or marks it closed once it's gone.
+    pub fn follow(&mut self, tcp: &[TcpStats], udp: &[UdpStats]) {
+        match SocketSnapshot::find(&self.key, tcp, udp) {
+            Some(snapshot) => self.last = snapshot,
+            None => self.closed = true,
+        }
+    }
+}
+
+// Every connection in the latest socket snapshot, with what the lifecycle tracking needs to know
+// about it. Connections that were already open on the first tick have `seen_at_start` set.
+pub struct TrackedConnection {
+    pub first_seen: Instant,
+    pub seen_at_start: bool,
+    pub state: &'static str,
+    pub process: Option<String>,
+}
+
+// Lifecycle events since startup or the last namespace switch.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct LifecycleCounts {
+    pub opened: u64,
+    pub state_changes: u64,
+    pub closed: u64,
+}
+
+// Sockets table order, toggled with `s`. Top flows puts the busiest connections first, by
+// combined RX and TX rate; sockets without tcp_info sort last.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum SocketSort {
+    #[default]
+    Kernel,
+    TopFlows,
+}
+
+impl SocketSort {
+    pub fn next(&self) -> Self {
+        match self {
+            SocketSort::Kernel => SocketSort::TopFlows,
+            SocketSort::TopFlows => SocketSort::Kernel,
+        }
+    }
+
+    pub fn title(&self) -> &'static str {
+        match self {
+            SocketSort::Kernel => "kernel",
+            SocketSort::TopFlows => "top flows",
+        }
+    }
+
+    pub fn sort(&self, sockets: &mut [&dyn Socket], rates: &HashMap<SocketKey, (f64, f64)>) {
+        if *self == SocketSort::TopFlows {
+            let total = |conn: &dyn Socket| {
+                rates
+                    .get(&conn.socket_key())
+                    .map_or(-1.0, |(rx, tx)| rx + tx)
+            };
+            sockets.sort_by(|a, b| total(*b).total_cmp(&total(*a)));
+        }
+    }
+}
+
+// What the bottom half of the screen shows.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum Panel {
+    #[default]
+    Sockets,
+    Processes,
+    Recent,
+    Protocols,
+    Routes,
+    Neighbors,
+    Conntrack,
+    Softnet,
+    Irqs,
+}
+
+impl Panel {
+    pub fn next(&self) -> Self {
+        match self {
+            Panel::Sockets => Panel::Processes,
+            Panel::Processes => Panel::Recent,
+            Panel::Recent => Panel::Protocols,
+            Panel::Protocols => Panel::Routes,
+            Panel::Routes => Panel::Neighbors,
+            Panel::Neighbors => Panel::Conntrack,
+            Panel::Conntrack => Panel::Softnet,
+            Panel::Softnet => Panel::Irqs,
+            Panel::Irqs => Panel::Sockets,
+        }
+    }
+
+    pub fn title(&self) -> &'static str {
+        match self {
+            Panel::Sockets => "Sockets",
+            Panel::Processes => "TCP Processes",
+            Panel::Recent => "Recent",
+            Panel::Protocols => "Protocol Statistics",
+            Panel::Routes => "Routes",
+            Panel::Neighbors => "Neighbors",
+            Panel::Conntrack => "Conntrack",
+            Panel::Softnet => "Softnet",
+            Panel::Irqs => "IRQs & Queues",
+        }
+    }
+}
+
+// Conntrack table order, cycled with `s`. Counters and timeouts sort largest first.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum ConntrackSort {
+    #[default]
+    Bytes,
+    Packets,
+    Timeout,
+    Protocol,
+    State,
+    Source,
+    Destination,
+}
+
+impl ConntrackSort {
+    pub fn next(&self) -> Self {
+        match self {
+            ConntrackSort::Bytes => ConntrackSort::Packets,
+            ConntrackSort::Packets => ConntrackSort::Timeout,
+            ConntrackSort::Timeout => ConntrackSort::Protocol,
+            ConntrackSort::Protocol => ConntrackSort::State,
+            ConntrackSort::State => ConntrackSort::Source,
+            ConntrackSort::Source => ConntrackSort::Destination,
+            ConntrackSort::Destination => ConntrackSort::Bytes,
+        }
+    }
+
+    pub fn title(&self) -> &'static str {
+        match self {
+            ConntrackSort::Bytes => "bytes",
+            ConntrackSort::Packets => "packets",
+            ConntrackSort::Timeout => "timeout",
+            ConntrackSort::Protocol => "protocol",
+            ConntrackSort::State => "state",
+            ConntrackSort::Source => "source",
+            ConntrackSort::Destination => "destination",
+        }
+    }
+
+    pub fn sort(&self, entries: &mut [ConntrackEntry]) {
+        let endpoint = |t: &ConntrackTuple, port: Option<u16>| (t.src, t.dst, port);
+        match self {
+            ConntrackSort::Bytes => entries.sort_by_key(|e| std::cmp::Reverse(e.bytes())),
+            ConntrackSort::Packets => entries.sort_by_key(|e| std::cmp::Reverse(e.packets())),
+            ConntrackSort::Timeout => entries.sort_by_key(|e| std::cmp::Reverse(e.timeout)),
+            ConntrackSort::Protocol => entries.sort_by(|a, b| a.protocol.cmp(&b.protocol)),
+            ConntrackSort::State => entries.sort_by(|a, b| a.state.cmp(&b.state)),
+            ConntrackSort::Source => {
+                entries.sort_by_key(|e| endpoint(&e.original, e.original.sport))
+            }
+            ConntrackSort::Destination => entries.sort_by_key(|e| {
+                let (src, dst, port) = endpoint(&e.original, e.original.dport);
+                (dst, port, src)
+            }),
+        }
+    }
+}
+
+// Where socket tables and interface counters come from. Netlink sock_diag avoids formatting
+// and re-parsing /proc/net/{tcp,udp} on busy hosts and also returns tcp_info for each socket;
+// rtnetlink gives 64-bit link counters plus metadata /proc/net/dev doesn't carry.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Backend {
+    Netlink,
+    Procfs,
+}
+
+impl Backend {
+    pub fn detect_sockets() -> Self {
+        if netlink_tcp_stats().is_ok() && netlink_udp_stats().is_ok() {
+            Backend::Netlink
+        } else {
+            Backend::Procfs
+        }
+    }
+
+    pub fn detect_links() -> Self {
+        if netlink_network_stats().is_ok_and(|links| !links.is_empty()) {
+            Backend::Netlink
+        } else {
+            Backend::Procfs
+        }
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Backend::Netlink => "netlink",
+            Backend::Procfs => "procfs",
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
 pub enum InterfaceSelected {
     All,
     Interface(String),
@@ -129,45 +517,792 @@ impl App {
         let now = self.start_time.elapsed().as_secs_f64();
         self.window = [now - 5.0, now];
 
-        let net_vec_stats = parse_proc_net_dev()?;
-        let tcp_stats = parse_proc_net_tcp()?;
+        let net_vec_stats = self.read_interfaces()?;
+        let (tcp_stats, udp_stats) = self.read_sockets()?;
+        self.update_flow_rates(now, &tcp_stats);
+        self.track_connections(&tcp_stats, &udp_stats);
+        // Traffic to one of our own addresses goes through the local table and `lo`, which
+        // /proc/net/route doesn't show. Both ends of such a connection are listed here.
+        self.local_addrs = tcp_stats
+            .iter()
+            .map(|s| s.local_ip().to_canonical())
+            .chain(udp_stats.iter().map(|s| s.local_ip().to_canonical()))
+            .filter(|ip| !ip.is_unspecified())
+            .collect();
         self.tcp_stats = Some(tcp_stats);
+        self.udp_stats = Some(udp_stats);
+        self.update_locked_socket();
+        self.refresh_tcp_details();
+        if self.in_home_netns() {
+            // Cheap sysfs reads, redone every tick since autonegotiation and wireless rates change.
+            self.detected_speeds = net_vec_stats
+                .iter()
+                .filter_map(|i| detect_link_speed(&i.name).map(|s| (i.name.clone(), s)))
+                .collect();
+        }
+        if let Ok(stats) = parse_proto_stats() {
+            self.prev_proto_stats = self.proto_stats.replace((now, stats));
+        }
+        self.sockstat = parse_sockstat().ok();
+        if let Ok(stats) = read_cpu_net_stats() {
+            self.prev_cpu_net_stats = self.cpu_net_stats.replace((now, stats));
+        }
+        self.routes = parse_proc_net_route().unwrap_or_default();
+        self.read_neighbors();
+        match self.panel {
+            Panel::Conntrack => self.read_conntrack(),
+            Panel::Irqs => self.read_irqs(),
+            _ => {}
+        }
+        self.read_wireless();
+        self.update_veth_peers(&net_vec_stats);
+        self.parents = read_topology(&net_vec_stats, self.in_home_netns());
+        self.bonds = parse_proc_net_bonding();
 
         if let Some(prev_data) = &self.prev_stats {
-            for (prev, new) in prev_data.iter().zip(net_vec_stats.iter()) {
-                let rx_delta = new.receive.bytes.saturating_sub(prev.receive.bytes);
-                let tx_delta = new.transmit.bytes.saturating_sub(prev.transmit.bytes);
-
-                self.rx_data
-                    .entry(new.name.clone())
-                    .or_insert_with(Vec::new)
-                    .push((now, rx_delta as f64));
-
-                self.tx_data
-                    .entry(new.name.clone())
-                    .or_insert_with(Vec::new)
-                    .push((now, tx_delta as f64));
-
-                if let Some(rx_vec) = self.rx_data.get_mut(&new.name) {
-                    if rx_vec.len() > 100 {
-                        rx_vec.remove(0);
-                    }
+            let prev_by_name: HashMap<&str, &NetworkStats> =
+                prev_data.iter().map(|s| (s.name.as_str(), s)).collect();
+
+            for new in &net_vec_stats {
+                // New interfaces have no baseline yet.
+                let Some(prev) = prev_by_name.get(new.name.as_str()) else {
+                    continue;
+                };
+                let (rx_delta, tx_delta) = if same_link(prev, new) {
+                    (
+                        counter_delta(prev.receive.bytes, new.receive.bytes),
+                        counter_delta(prev.transmit.bytes, new.transmit.bytes),
+                    )
+                } else {
+                    (CounterDelta::Reset, CounterDelta::Reset)
+                };
+
+                let last_time = self
+                    .rx_data
+                    .get(&new.name)
+                    .and_then(|s| s.last())
+                    .map(|(t, _)| *t);
+                if let Some(dt) = last_time.map(|t| now - t).filter(|dt| *dt > 0.0) {
+                    let rates = |before: Vec<(&str, Option<u64>)>,
+                                 after: Vec<(&str, Option<u64>)>| {
+                        before
+                            .iter()
+                            .zip(&after)
+                            .map(|((_, a), (_, b))| match (a, b) {
+                                (Some(a), Some(b)) => b.saturating_sub(*a) as f64 / dt,
+                                _ => 0.0,
+                            })
+                            .collect()
+                    };
+                    self.counter_rates.insert(
+                        new.name.clone(),
+                        (
+                            rates(prev.receive.counters(), new.receive.counters()),
+                            rates(prev.transmit.counters(), new.transmit.counters()),
+                        ),
+                    );
                 }
-                if let Some(tx_vec) = self.tx_data.get_mut(&new.name) {
-                    if tx_vec.len() > 100 {
-                        tx_vec.remove(0);
+
+                for (delta, data, peaks, avgs) in [
+                    (
+                        rx_delta,
+                        &mut self.rx_data,
+                        &mut self.rx_peak_speed,
+                        &mut self.rx_avg_speed,
+                    ),
+                    (
+                        tx_delta,
+                        &mut self.tx_data,
+                        &mut self.tx_peak_speed,
+                        &mut self.tx_avg_speed,
+                    ),
+                ] {
+                    let samples = data.entry(new.name.clone()).or_default();
+                    let last_time = samples.last().map(|(t, _)| *t);
+
+                    let bytes = match delta {
+                        CounterDelta::Bytes(bytes) => Some(bytes as f64),
+                        CounterDelta::Wrapped => None,
+                        // Peak and average belong to the previous incarnation of the counter.
+                        CounterDelta::Reset => {
+                            peaks.remove(&new.name);
+                            avgs.remove(&new.name);
+                            None
+                        }
+                    };
+                    samples.push((now, bytes));
+                    if samples.len() > 100 {
+                        samples.remove(0);
+                    }
+
+                    if let (Some(bytes), Some(last_time)) = (bytes, last_time) {
+                        let rate = bytes / (now - last_time) / (1024.0 * 1024.0);
+                        let peak = peaks.entry(new.name.clone()).or_insert(0.0);
+                        *peak = peak.max(rate);
+                        let avg = avgs.entry(new.name.clone()).or_insert(0.0);
+                        *avg = *avg * 0.95 + rate * 0.05;
                     }
                 }
             }
         }
+        self.track_gone_interfaces(&net_vec_stats);
         self.prev_stats = Some(net_vec_stats);
 
         Ok(())
     }
 
-    pub fn push_total_vals(&mut self, total_rx_mbs: f64, total_tx_mbs: f64) {
-        let rx = total_rx_mbs.max(0.0).round() as u64;
-        let tx = total_tx_mbs.max(0.0).round() as u64;
+    fn track_gone_interfaces(&mut self, current: &[NetworkStats]) {
+        if let Some(prev_data) = &self.prev_stats {
+            for prev in prev_data {
+                if !current.iter().any(|s| s.name == prev.name) {
+                    self.gone_interfaces
+                        .insert(prev.name.clone(), (prev.clone(), Instant::now()));
+                }
+            }
+        }
+        self.gone_interfaces
+            .retain(|name, _| !current.iter().any(|s| &s.name == name));
+
+        let expired: Vec<String> = self
+            .gone_interfaces
+            .iter()
+            .filter(|(_, (_, since))| since.elapsed() > INTERFACE_GRACE)
+            .map(|(name, _)| name.clone())
+            .collect();
+        for name in expired {
+            self.gone_interfaces.remove(&name);
+            self.rx_data.remove(&name);
+            self.tx_data.remove(&name);
+            self.counter_rates.remove(&name);
+            self.rx_peak_speed.remove(&name);
+            self.tx_peak_speed.remove(&name);
+            self.rx_avg_speed.remove(&name);
+            self.tx_avg_speed.remove(&name);
+            self.signal_history.remove(&name);
+            if self.selected_interface == InterfaceSelected::Interface(name) {
+                self.selected_interface = InterfaceSelected::All;
+            }
+        }
+    }
+
+    // `all`, the live interfaces in kernel order, then recently vanished ones.
+    pub fn interface_names(&self) -> VecDeque<String> {
+        self.interface_rows().into_iter().map(|r| r.name).collect()
+    }
+
+    // The interface list as a tree: each interface is followed by its ports, slaves and VLANs,
+    // unless collapsed. Filtering searches everything, so nothing is hidden while it's open.
+    pub fn interface_rows(&self) -> Vec<InterfaceRow> {
+        let live: Vec<&String> = self.prev_stats.iter().flatten().map(|s| &s.name).collect();
+        let expand_all = matches!(self.mode, Mode::SelectingInterface { .. });
+
+        let mut rows = vec![InterfaceRow {
+            name: String::from("all"),
+            depth: 0,
+            children: 0,
+        }];
+        let mut stack: Vec<(&String, usize)> = live
+            .iter()
+            .rev()
+            .filter(|name| !self.parents.contains_key(**name))
+            .map(|name| (*name, 0))
+            .collect();
+        let mut children: HashMap<&String, Vec<&String>> = HashMap::new();
+        for name in &live {
+            if let Some(parent) = self.parents.get(*name) {
+                children.entry(parent).or_default().push(name);
+            }
+        }
+
+        let mut seen = HashSet::new();
+        while let Some((name, depth)) = stack.pop() {
+            if !seen.insert(name) {
+                continue;
+            }
+            let members = children.get(name).map(Vec::as_slice).unwrap_or_default();
+            rows.push(InterfaceRow {
+                name: name.clone(),
+                depth,
+                children: members.len(),
+            });
+            if expand_all || !self.collapsed.contains(name) {
+                stack.extend(members.iter().rev().map(|c| (*c, depth + 1)));
+            }
+        }
+
+        let mut gone: Vec<&String> = self.gone_interfaces.keys().collect();
+        gone.sort();
+        rows.extend(gone.into_iter().map(|name| InterfaceRow {
+            name: name.clone(),
+            depth: 0,
+            children: 0,
+        }));
+        rows
+    }
+
+    // Per-connection throughput from tcp_info, also summed per owning process, nethogs style.
+    fn update_flow_rates(&mut self, now: f64, tcp_stats: &[TcpStats]) {
+        let process_cache = Arc::clone(&self.process_cache_arc);
+        let process_cache = process_cache.lock().unwrap();
+        let deltas = flow_deltas(&mut self.flow_bytes, now, tcp_stats);
+        self.flow_rates = deltas
+            .iter()
+            .map(|(key, d)| (*key, (d.rx_rate, d.tx_rate)))
+            .collect();
+        account_process_traffic(
+            &mut self.process_traffic,
+            tcp_stats,
+            &deltas,
+            &process_cache,
+        );
+
+        // Session totals stay while the process lives, even between connections. Processes
+        // that own no socket right now are missing from the cache, so /proc decides.
+        let live: HashSet<u32> = process_cache.values().map(|p| p.pid).collect();
+        self.process_traffic
+            .retain(|pid, _| live.contains(pid) || Path::new(&format!("/proc/{}", pid)).exists());
+    }
+
+    // Processes view rows, busiest first by current rate and then by session bytes.
+    pub fn process_rows(&self) -> Vec<&ProcessTraffic> {
+        let mut rows: Vec<&ProcessTraffic> = self.process_traffic.values().collect();
+        rows.sort_by(|a, b| {
+            (b.rx_rate + b.tx_rate)
+                .total_cmp(&(a.rx_rate + a.tx_rate))
+                .then((b.rx_bytes + b.tx_bytes).cmp(&(a.rx_bytes + a.tx_bytes)))
+                .then(a.pid.cmp(&b.pid))
+        });
+        rows
+    }
+
+    // Row of the selected process in `process_rows()`, or the first row once it is gone.
+    pub fn process_cursor(&self, rows: &[&ProcessTraffic]) -> usize {
+        self.selected_process
+            .and_then(|pid| rows.iter().position(|p| p.pid == pid))
+            .unwrap_or(0)
+    }
+
+    fn move_process_cursor(&mut self, down: bool) {
+        let rows = self.process_rows();
+        let cursor = self.process_cursor(&rows);
+        let cursor = if down {
+            (cursor + 1).min(rows.len().saturating_sub(1))
+        } else {
+            cursor.saturating_sub(1)
+        };
+        self.selected_process = rows.get(cursor).map(|p| p.pid);
+    }
+
+    // Shows the sockets of the process under the cursor in the Processes view, as a `pid=N`
+    // filter on the sockets table.
+    fn filter_by_process(&mut self) {
+        let rows = self.process_rows();
+        let Some(pid) = rows.get(self.process_cursor(&rows)).map(|p| p.pid) else {
+            return;
+        };
+        self.panel = Panel::Sockets;
+        self.locked = None;
+        self.tcp_vertical_scroll = 0;
+        self.mode = Mode::FilterLocalAddress {
+            filter: format!("pid={}", pid),
+            index: 0,
+        };
+    }
+
+    // Interface a connection's packets leave through, by longest-prefix match on the remote
+    // address. Loopback and connections to one of our own addresses stay on `lo`.
+    pub fn egress_interface(&self, conn: &dyn Socket) -> Option<String> {
+        let remote = conn.remote_ip().to_canonical();
+        if remote.is_unspecified() {
+            return None;
+        }
+        if remote.is_loopback() || self.local_addrs.contains(&remote) {
+            return Some("lo".to_string());
+        }
+        route_lookup(&self.routes, &remote).map(|r| r.interface.clone())
+    }
+
+    // Diffs the new socket snapshot against the tracked connections. Must run before the new
+    // snapshot replaces the old one, which still holds the last state of closed connections.
+    fn track_connections(&mut self, tcp_stats: &[TcpStats], udp_stats: &[UdpStats]) {
+        let now = Instant::now();
+        let first_tick = self.sockets_read_at.is_none();
+        let current: HashMap<ConnectionKey, &'static str> = tcp_stats
+            .iter()
+            .map(|s| (s.connection_key(), s.state_name()))
+            .chain(
+                udp_stats
+                    .iter()
+                    .map(|s| (s.connection_key(), s.state_name())),
+            )
+            .collect();
+        let prev: HashMap<ConnectionKey, &'static str> = self
+            .connections
+            .iter()
+            .map(|(key, c)| (*key, c.state))
+            .collect();
+
+        for event in diff_sockets(&prev, &current) {
+            match event {
+                SocketEvent::Opened(key) => {
+                    if !first_tick {
+                        self.lifecycle_counts.opened += 1;
+                    }
+                    self.connections.insert(
+                        key,
+                        TrackedConnection {
+                            first_seen: now,
+                            seen_at_start: first_tick,
+                            state: current[&key],
+                            process: None,
+                        },
+                    );
+                }
+                SocketEvent::StateChanged(key, _, state) => {
+                    self.lifecycle_counts.state_changes += 1;
+                    if let Some(c) = self.connections.get_mut(&key) {
+                        c.state = state;
+                    }
+                }
+                SocketEvent::Closed(key) => {
+                    self.lifecycle_counts.closed += 1;
+                    let Some(c) = self.connections.remove(&key) else {
+                        continue;
+                    };
+                    let Some(last) = self.find_connection(&key) else {
+                        continue;
+                    };
+                    self.recent.push_front(ClosedConnection {
+                        last,
+                        process: c.process,
+                        first_seen: c.first_seen,
+                        seen_at_start: c.seen_at_start,
+                        last_seen: self.sockets_read_at.unwrap_or(now),
+                        closed_at: now,
+                    });
+                }
+            }
+        }
+        self.recent.truncate(RECENT_CAPACITY);
+
+        // The process index lags new sockets by up to PROCESS_INDEX_INTERVAL, so owners are
+        // looked up until found.
+        let process_cache = self.process_cache_arc.lock().unwrap();
+        let sockets = tcp_stats
+            .iter()
+            .map(|s| s as &dyn Socket)
+            .chain(udp_stats.iter().map(|s| s as &dyn Socket));
+        for conn in sockets.filter(|conn| conn.inode() != 0) {
+            if let Some(c) = self.connections.get_mut(&conn.connection_key()) {
+                if c.process.is_none() {
+                    c.process = process_cache.get(&conn.inode()).map(|p| p.label());
+                }
+            }
+        }
+        drop(process_cache);
+        self.sockets_read_at = Some(now);
+    }
+
+    // Owned copy of a connection from the latest tick, whatever socket now backs it.
+    fn find_connection(&self, key: &ConnectionKey) -> Option<SocketSnapshot> {
+        match key.0 {
+            Protocol::Tcp => self
+                .tcp_stats
+                .iter()
+                .flatten()
+                .find(|s| s.connection_key() == *key)
+                .cloned()
+                .map(SocketSnapshot::Tcp),
+            Protocol::Udp => self
+                .udp_stats
+                .iter()
+                .flatten()
+                .find(|s| s.connection_key() == *key)
+                .cloned()
+                .map(SocketSnapshot::Udp),
+        }
+    }
+
+    // Whether a connection first appeared within the highlight window, not counting the ones
+    // already there at startup.
+    pub fn is_new_connection(&self, conn: &dyn Socket) -> bool {
+        self.connections
+            .get(&conn.connection_key())
+            .is_some_and(|c| !c.seen_at_start && c.first_seen.elapsed() < CONNECTION_HIGHLIGHT)
+    }
+
+    // Connections closed within the highlight window, which the sockets table keeps showing.
+    pub fn just_closed(&self) -> impl Iterator<Item = &ClosedConnection> {
+        self.recent
+            .iter()
+            .take_while(|c| c.closed_at.elapsed() < CONNECTION_HIGHLIGHT)
+    }
+
+    // Locks onto the socket at `index` of the filtered sockets table, in on-screen order.
+    fn lock_socket(&mut self, filter: &str, index: usize) {
+        let key = {
+            let hostname_cache = self.hostname_cache_arc.lock().unwrap();
+            let process_cache = self.process_cache_arc.lock().unwrap();
+            let mut sockets = collect_sockets(
+                self.socket_view,
+                self.tcp_stats.as_deref().unwrap_or_default(),
+                self.udp_stats.as_deref().unwrap_or_default(),
+            );
+            self.socket_sort.sort(&mut sockets, &self.flow_rates);
+            sockets
+                .into_iter()
+                .filter(|conn| {
+                    socket_matches_filter(*conn, filter, &hostname_cache, &process_cache)
+                })
+                .nth(index)
+                .map(|conn| conn.socket_key())
+        };
+        self.locked = key.and_then(|key| {
+            LockedSocket::new(
+                key,
+                self.tcp_stats.as_deref().unwrap_or_default(),
+                self.udp_stats.as_deref().unwrap_or_default(),
+            )
+        });
+    }
+
+    fn update_locked_socket(&mut self) {
+        if let Some(locked) = &mut self.locked {
+            locked.follow(
+                self.tcp_stats.as_deref().unwrap_or_default(),
+                self.udp_stats.as_deref().unwrap_or_default(),
+            );
+        }
+    }
+
+    // Queries inet_diag for the health popup of the locked connection, if it is a TCP one. A
+    // closed connection keeps its last figures.
+    fn refresh_tcp_details(&mut self) {
+        match &self.locked {
+            Some(locked) if locked.closed => {}
+            Some(LockedSocket {
+                key: (Protocol::Tcp, flow, _),
+                ..
+            }) => self.tcp_details = netlink_tcp_details(*flow).ok(),
+            _ => self.tcp_details = None,
+        }
+    }
+
+    // Makes the egress interface of the locked connection the selected one, as if it had been
+    // picked from the interface list.
+    fn jump_to_egress(&mut self) {
+        let egress = self
+            .locked
+            .as_ref()
+            .and_then(|l| self.egress_interface(l.last.socket()));
+        let Some(egress) = egress else {
+            return;
+        };
+        let Some(row) = self.interface_names().iter().position(|n| *n == egress) else {
+            return;
+        };
+
+        self.selected_interface = InterfaceSelected::Interface(egress);
+        self.vertical_scroll = row;
+        self.update_scroll_state();
+        self.focus = Focus::Interfaces;
+        self.locked = None;
+        self.mode = Mode::Normal;
+    }
+
+    // Live members of a bridge, bond or VLAN parent, in kernel order.
+    pub fn children_of(&self, parent: &str) -> Vec<&NetworkStats> {
+        self.prev_stats
+            .iter()
+            .flatten()
+            .filter(|s| self.parents.get(&s.name).is_some_and(|p| p == parent))
+            .collect()
+    }
+
+    // Summed last-tick RX/TX rates of a parent's direct members, in bytes per second.
+    pub fn children_rate(&self, parent: &str) -> (f64, f64) {
+        self.children_of(parent)
+            .iter()
+            .fold((0.0, 0.0), |(rx, tx), child| {
+                let rate = |data: &HashMap<String, Vec<Sample>>| {
+                    data.get(&child.name)
+                        .and_then(|s| last_rate(s))
+                        .unwrap_or(0.0)
+                };
+                (rx + rate(&self.rx_data), tx + rate(&self.tx_data))
+            })
+    }
+
+    // /sys/class/net shows the namespace sysfs was mounted from, not the one we switched to.
+    pub fn in_home_netns(&self) -> bool {
+        self.home_netns
+            .as_ref()
+            .is_none_or(|(_, home)| home.inode == self.netns.inode)
+    }
+
+    // Moves collection into another network namespace. If the first read there fails, collection
+    // goes back to the previous namespace so there is always something to draw.
+    pub fn switch_netns(&mut self, ns: NetNs) -> Result<()> {
+        let previous = self.netns.clone();
+        self.move_to_netns(ns)?;
+        let Err(e) = self.get_stuff() else {
+            return Ok(());
+        };
+        if self.move_to_netns(previous).is_ok() {
+            let _ = self.get_stuff();
+        }
+        Err(e)
+    }
+
+    // Everything keyed by interface name or derived from the old namespace's counters is dropped.
+    fn move_to_netns(&mut self, ns: NetNs) -> Result<()> {
+        match &self.home_netns {
+            Some((file, home)) if home.inode == ns.inode => enter_netns(file)?,
+            _ => enter_netns(&open_netns(&ns.path)?)?,
+        }
+        self.netns = ns;
+
+        self.prev_stats = None;
+        self.gone_interfaces.clear();
+        self.rx_data.clear();
+        self.counter_rates.clear();
+        self.tx_data.clear();
+        self.rx_peak_speed.clear();
+        self.tx_peak_speed.clear();
+        self.rx_avg_speed.clear();
+        self.tx_avg_speed.clear();
+        self.total_rx_history.clear();
+        self.total_tx_history.clear();
+        self.detected_speeds.clear();
+        self.proto_stats = None;
+        self.prev_proto_stats = None;
+        self.flow_bytes.clear();
+        self.flow_rates.clear();
+        self.process_traffic.clear();
+        self.connections.clear();
+        self.recent.clear();
+        self.lifecycle_counts = LifecycleCounts::default();
+        self.sockets_read_at = None;
+        self.signal_history.clear();
+        self.veth_peers.clear();
+        self.veth_links.clear();
+        self.collapsed.clear();
+        self.neighbors.clear();
+        self.mac_changes.clear();
+        self.conntrack.clear();
+        self.conntrack_backend = Backend::Netlink;
+        self.selected_interface = InterfaceSelected::All;
+        self.vertical_scroll = 0;
+        self.tcp_vertical_scroll = 0;
+        self.locked = None;
+        self.socket_backend = Backend::detect_sockets();
+        self.link_backend = Backend::detect_links();
+        Ok(())
+    }
+
+    // A MAC that differs from the previous tick for the same IP and device is flagged; an entry
+    // merely resolving (incomplete -> complete) is not a change.
+    fn read_neighbors(&mut self) {
+        let mut neighbors = parse_proc_net_arp().unwrap_or_default();
+        neighbors.extend(netlink_ipv6_neighbors().unwrap_or_default());
+
+        let previous: HashMap<(IpAddr, &str), &str> = self
+            .neighbors
+            .iter()
+            .filter_map(|n| Some(((n.ip, n.device.as_str()), n.mac.as_deref()?)))
+            .collect();
+        for n in &neighbors {
+            let Some(mac) = &n.mac else {
+                continue;
+            };
+            if let Some(old) = previous.get(&(n.ip, n.device.as_str())) {
+                if old != mac {
+                    self.mac_changes
+                        .insert((n.ip, n.device.clone()), (old.to_string(), Instant::now()));
+                }
+            }
+        }
+        self.mac_changes
+            .retain(|_, (_, at)| at.elapsed() < MAC_CHANGE_HOLD);
+        self.neighbors = neighbors;
+    }
+
+    // A big conntrack table is expensive to dump, so it is only read while its panel is shown.
+    // ctnetlink needs nf_conntrack_netlink and CAP_NET_ADMIN; /proc/net/nf_conntrack doesn't.
+    fn read_conntrack(&mut self) {
+        self.conntrack_usage = read_conntrack_usage();
+        if self.conntrack_backend == Backend::Netlink {
+            match netlink_conntrack() {
+                Ok(entries) => self.conntrack = entries,
+                Err(_) => self.conntrack_backend = Backend::Procfs,
+            }
+        }
+        if self.conntrack_backend == Backend::Procfs {
+            self.conntrack = parse_proc_net_nf_conntrack().unwrap_or_default();
+        }
+        self.conntrack_sort.sort(&mut self.conntrack);
+    }
+
+    // The selected interface, or the first one backed by a device when 'All' is selected. Outside
+    // the home namespace sysfs can't tell, so that is the first one other than loopback.
+    pub fn irq_interface(&self) -> Option<String> {
+        let home = self.in_home_netns();
+        match &self.selected_interface {
+            InterfaceSelected::Interface(name) => Some(name.clone()),
+            InterfaceSelected::All => self.interface_names().into_iter().find(|name| {
+                if home {
+                    interface_driver(name).is_some()
+                } else {
+                    name != "lo"
+                }
+            }),
+        }
+    }
+
+    // Only the interface's own IRQs are kept; /proc/interrupts is global, but its device, MSI
+    // vectors and queues come from sysfs, which only describes the namespace it was mounted in.
+    fn read_irqs(&mut self) {
+        let now = self.start_time.elapsed().as_secs_f64();
+        let Some(interface) = self.irq_interface() else {
+            self.irq_stats = None;
+            self.net_queues = None;
+            return;
+        };
+        if let Ok((cpus, irqs)) = parse_proc_interrupts() {
+            let irqs: Vec<IrqStats> = interface_irqs(&interface, &irqs, self.in_home_netns())
+                .into_iter()
+                .cloned()
+                .collect();
+            self.irq_affinity = irqs
+                .iter()
+                .filter_map(|irq| Some((irq.irq, irq_affinity(irq.irq)?)))
+                .collect();
+            self.irq_cpus = cpus;
+            self.prev_irq_stats = self.irq_stats.replace((now, irqs));
+        }
+        let queues = if self.in_home_netns() {
+            read_net_queues(&interface)
+        } else {
+            Vec::new()
+        };
+        self.prev_net_queues = self.net_queues.replace((now, interface, queues));
+    }
+
+    // /proc/net/wireless only exists once a wireless driver is loaded.
+    fn read_wireless(&mut self) {
+        self.wireless_stats = parse_proc_net_wireless()
+            .unwrap_or_default()
+            .into_iter()
+            .map(|w| (w.name.clone(), w))
+            .collect();
+
+        for (name, wireless) in &self.wireless_stats {
+            let history = self.signal_history.entry(name.clone()).or_default();
+            history.push(wireless.level);
+            if history.len() > self.history_capacity {
+                history.remove(0);
+            }
+        }
+    }
+
+    // Re-resolves veth peers when the set of veths changes, or periodically while some are
+    // still unattributed. Each resolution scans every process and enters every namespace, so it
+    // runs on a background thread and its result is picked up on a later tick.
+    fn update_veth_peers(&mut self, current: &[NetworkStats]) {
+        let in_home = self.in_home_netns();
+        let mut links: Vec<VethLink> = current
+            .iter()
+            .filter_map(|i| {
+                let (ifindex, iflink, netnsid) = match &i.link {
+                    Some(link) if link.kind.as_deref() == Some("veth") => {
+                        (link.ifindex, link.link?, link.link_netnsid)
+                    }
+                    Some(_) => return None,
+                    None if in_home => {
+                        let (ifindex, iflink) = read_sysfs_iflink(&i.name)?;
+                        (ifindex, iflink, None)
+                    }
+                    None => return None,
+                };
+                (ifindex != iflink).then(|| VethLink {
+                    name: i.name.clone(),
+                    ifindex,
+                    iflink,
+                    netnsid,
+                })
+            })
+            .collect();
+        links.sort();
+
+        // A result for another namespace or an older set of veths is stale.
+        let finished = self.veth_result.lock().unwrap().take();
+        if let Some((inode, resolved, peers)) = finished {
+            self.veth_resolving = false;
+            if inode == self.netns.inode && resolved == links {
+                self.veth_peers = peers;
+            }
+        }
+        if self.veth_resolving {
+            return;
+        }
+
+        let unresolved = links
+            .iter()
+            .any(|veth| !self.veth_peers.contains_key(&veth.name));
+        let retry = unresolved
+            && self
+                .veth_resolved_at
+                .is_none_or(|at| at.elapsed() >= VETH_RETRY);
+        if links == self.veth_links && !retry {
+            return;
+        }
+
+        self.veth_links = links.clone();
+        self.veth_resolved_at = Some(Instant::now());
+        if links.is_empty() {
+            self.veth_peers.clear();
+            return;
+        }
+
+        // New threads start in the namespace of the one spawning them, i.e. the current one.
+        self.veth_resolving = true;
+        let inode = self.netns.inode;
+        let result = Arc::clone(&self.veth_result);
+        std::thread::spawn(move || {
+            let peers = resolve_veth_peers(&links, inode);
+            *result.lock().unwrap() = Some((inode, links, peers));
+        });
+    }
+
+    fn read_interfaces(&mut self) -> Result<Vec<NetworkStats>> {
+        if self.link_backend == Backend::Netlink {
+            if let Ok(stats) = netlink_network_stats() {
+                return Ok(stats);
+            }
+            self.link_backend = Backend::Procfs;
+        }
+        let mut stats = parse_proc_net_dev()?;
+        if self.in_home_netns() {
+            read_sysfs_statistics(&mut stats);
+        }
+        Ok(stats)
+    }
+
+    // Falls back to procfs for good if a netlink dump ever fails (e.g. the diag module got unloaded).
+    fn read_sockets(&mut self) -> Result<(Vec<TcpStats>, Vec<UdpStats>)> {
+        if self.socket_backend == Backend::Netlink {
+            if let (Ok(tcp), Ok(udp)) = (netlink_tcp_stats(), netlink_udp_stats()) {
+                return Ok((tcp, udp));
+            }
+            self.socket_backend = Backend::Procfs;
+        }
+        Ok((parse_proc_net_tcp()?, parse_proc_net_udp()?))
+    }
+
+    // `None` marks a tick where some interface's counters were discontinuous.
+    pub fn push_total_vals(&mut self, total_rx_mbs: Option<f64>, total_tx_mbs: Option<f64>) {
+        let rx = total_rx_mbs.map(|v| v.max(0.0).round() as u64);
+        let tx = total_tx_mbs.map(|v| v.max(0.0).round() as u64);
 
         self.total_rx_history.push(rx);
         self.total_tx_history.push(tx);
@@ -182,19 +1317,21 @@ impl App {
         }
     }
 
+    // Walking every process's fd table is too slow for the draw loop, so the
+    // inode -> process index is rebuilt on its own thread.
+    pub fn spawn_process_indexer(&self) {
+        let cache = Arc::clone(&self.process_cache_arc);
+        std::thread::spawn(move || loop {
+            let index = build_socket_process_index();
+            *cache.lock().unwrap() = index;
+            std::thread::sleep(PROCESS_INDEX_INTERVAL);
+        });
+    }
+
     pub fn run(&mut self, terminal: &mut DefaultTerminal) -> Result<()> {
+        self.spawn_process_indexer();
         let mut last_tick = Instant::now();
-        let mut interface_name_vec: VecDeque<String> = parse_proc_net_dev()?
-            .iter()
-            .map(|s| s.name.clone())
-            .collect();
-
-        interface_name_vec.push_front(String::from("all"));
-
-        let mut rx_peak_speed: HashMap<String, f64> = HashMap::new();
-        let mut tx_peak_speed: HashMap<String, f64> = HashMap::new();
-        let mut rx_avg_speed: HashMap<String, f64> = HashMap::new();
-        let mut tx_avg_speed: HashMap<String, f64> = HashMap::new();
+        let mut interface_name_vec = self.interface_names();
 
         let new_len = interface_name_vec.len();
         self.vertical_scroll_state = self.vertical_scroll_state.content_length(new_len);
@@ -202,20 +1339,14 @@ impl App {
 
         loop {
             let tick_rate = self.tick_rate;
-            let latest_stats = self.prev_stats.clone().unwrap();
-
-            if let Some(tcp_stats) = self.tcp_stats.clone() {
-                let _ = terminal.draw(|frame| {
-                    self.render(
-                        frame,
-                        &latest_stats,
-                        &tcp_stats,
-                        &mut rx_peak_speed,
-                        &mut tx_peak_speed,
-                        &mut rx_avg_speed,
-                        &mut tx_avg_speed,
-                    )
-                });
+            // Nothing to draw until a tick has succeeded, e.g. after a failed namespace switch.
+            if let (Some(latest_stats), Some(tcp_stats), Some(udp_stats)) = (
+                self.prev_stats.clone(),
+                self.tcp_stats.clone(),
+                self.udp_stats.clone(),
+            ) {
+                let _ = terminal
+                    .draw(|frame| self.render(frame, &latest_stats, &tcp_stats, &udp_stats));
             }
 
             let mut timeout = tick_rate.saturating_sub(last_tick.elapsed());
@@ -242,7 +1373,7 @@ impl App {
                                     self.edit_rx_mode = true;
                                     self.editing_interface = Some(name.clone());
 
-                                    if let Some((rx, _)) = self.interface_speeds.get(name) {
+                                    if let (Some((rx, _)), _) = self.link_speed(name) {
                                         self.speed_input = rx.to_string();
                                     } else {
                                         self.speed_input.clear();
@@ -256,7 +1387,7 @@ impl App {
                                     self.edit_tx_mode = true;
                                     self.editing_interface = Some(name.clone());
 
-                                    if let Some((_, tx)) = self.interface_speeds.get(name) {
+                                    if let (Some((_, tx)), _) = self.link_speed(name) {
                                         self.speed_input = tx.to_string();
                                     } else {
                                         self.speed_input.clear();
@@ -264,13 +1395,11 @@ impl App {
                                 }
                             }
 
-                            KeyCode::Tab => {
-                                if self.main_tab_focus {
-                                    self.focus = match self.focus {
-                                        Focus::Interfaces => Focus::TcpTable,
-                                        Focus::TcpTable => Focus::Interfaces,
-                                    };
-                                }
+                            KeyCode::Tab if self.main_tab_focus => {
+                                self.focus = match self.focus {
+                                    Focus::Interfaces => Focus::TcpTable,
+                                    Focus::TcpTable => Focus::Interfaces,
+                                };
                             }
                             KeyCode::Char('f') => match self.focus {
                                 Focus::Interfaces => {
@@ -278,14 +1407,28 @@ impl App {
                                         filter: String::new(),
                                         index: 0,
                                     };
+                                    interface_name_vec = self.interface_names();
                                 }
-                                Focus::TcpTable => {
-                                    self.selected_index = None;
+                                Focus::TcpTable if self.panel == Panel::Sockets => {
+                                    self.locked = None;
                                     self.mode = Mode::FilterLocalAddress {
                                         filter: String::new(),
                                         index: 0,
                                     };
                                 }
+                                Focus::TcpTable if self.panel == Panel::Neighbors => {
+                                    self.mode = Mode::FilterNeighbors {
+                                        filter: String::new(),
+                                        index: 0,
+                                    };
+                                }
+                                Focus::TcpTable if self.panel == Panel::Conntrack => {
+                                    self.mode = Mode::FilterConntrack {
+                                        filter: String::new(),
+                                        index: 0,
+                                    };
+                                }
+                                Focus::TcpTable => {}
                             },
 
                             KeyCode::Char('K') => {
@@ -319,7 +1462,57 @@ impl App {
                             },
 
                             KeyCode::Right => self.scroll_right(),
+                            KeyCode::Char(' ') if self.focus == Focus::Interfaces => {
+                                let rows = self.interface_rows();
+                                if let Some(row) =
+                                    rows.get(self.vertical_scroll).filter(|r| r.children > 0)
+                                {
+                                    if !self.collapsed.remove(&row.name) {
+                                        self.collapsed.insert(row.name.clone());
+                                    }
+                                }
+                                interface_name_vec = self.interface_names();
+                                self.vertical_scroll_state = self
+                                    .vertical_scroll_state
+                                    .content_length(interface_name_vec.len());
+                            }
                             KeyCode::Char('r') => self.raw_bytes = !self.raw_bytes,
+                            KeyCode::Char('u') => {
+                                self.socket_view = self.socket_view.next();
+                                self.tcp_vertical_scroll = 0;
+                                self.tcp_update_scroll_state();
+                            }
+                            KeyCode::Char('p') => {
+                                self.panel = self.panel.next();
+                                match self.panel {
+                                    Panel::Conntrack => self.read_conntrack(),
+                                    Panel::Irqs => self.read_irqs(),
+                                    _ => {}
+                                }
+                            }
+                            KeyCode::Char('s') if self.panel == Panel::Conntrack => {
+                                self.conntrack_sort = self.conntrack_sort.next();
+                                self.conntrack_sort.sort(&mut self.conntrack);
+                            }
+                            KeyCode::Enter
+                                if self.panel == Panel::Processes
+                                    && self.focus == Focus::TcpTable =>
+                            {
+                                self.filter_by_process();
+                            }
+                            KeyCode::Char('s') if self.panel == Panel::Sockets => {
+                                self.socket_sort = self.socket_sort.next();
+                                self.tcp_vertical_scroll = 0;
+                                self.tcp_update_scroll_state();
+                            }
+                            KeyCode::Char('n') => {
+                                self.netns_list = list_namespaces();
+                                self.netns_error = None;
+                                self.mode = Mode::SelectingNetns {
+                                    filter: String::new(),
+                                    index: 0,
+                                };
+                            }
                             KeyCode::Char('d') => self.byte_unit = ByteUnit::Decimal,
                             KeyCode::Char('b') => self.byte_unit = ByteUnit::Binary,
                             KeyCode::Char('?') | KeyCode::Char('h') => {
@@ -338,12 +1531,10 @@ impl App {
                                 filter.pop();
                                 *index = 0;
                             }
-                            KeyCode::Up => {
-                                if *index > 0 {
-                                    *index -= 1;
-                                    self.theme_index = Some(*index);
-                                    self.current_theme = THEMES[*index].1();
-                                }
+                            KeyCode::Up if *index > 0 => {
+                                *index -= 1;
+                                self.theme_index = Some(*index);
+                                self.current_theme = THEMES[*index].1();
                             }
 
                             KeyCode::Down | KeyCode::Tab => {
@@ -388,6 +1579,43 @@ impl App {
                             _ => {}
                         },
 
+                        Mode::SelectingNetns { filter, index } => match key.code {
+                            KeyCode::Char(c) => {
+                                filter.push(c);
+                                *index = 0;
+                            }
+                            KeyCode::Backspace => {
+                                filter.pop();
+                                *index = 0;
+                            }
+                            KeyCode::Up if *index > 0 => {
+                                *index -= 1;
+                            }
+                            KeyCode::Down | KeyCode::Tab
+                                if *index + 1 < filter_netns(&self.netns_list, filter).len() =>
+                            {
+                                *index += 1;
+                            }
+                            KeyCode::Enter => {
+                                let selected = filter_netns(&self.netns_list, filter)
+                                    .get(*index)
+                                    .map(|ns| (*ns).clone());
+                                if let Some(ns) = selected {
+                                    match self.switch_netns(ns) {
+                                        Ok(()) => {
+                                            self.mode = Mode::Normal;
+                                            interface_name_vec = self.interface_names();
+                                        }
+                                        Err(e) => self.netns_error = Some(format!("{:#}", e)),
+                                    }
+                                }
+                            }
+                            KeyCode::Esc => {
+                                self.mode = Mode::Normal;
+                            }
+                            _ => {}
+                        },
+
                         Mode::SelectingInterface { filter, index } => match key.code {
                             KeyCode::Char('w') if key.modifiers.contains(KeyModifiers::CONTROL) => {
                                 filter.clear();
@@ -402,15 +1630,15 @@ impl App {
                             KeyCode::Backspace => {
                                 filter.pop();
                             }
-                            KeyCode::Up => {
-                                if *index > 0 {
-                                    *index -= 1;
-                                }
+                            KeyCode::Up if *index > 0 => {
+                                *index -= 1;
                             }
                             KeyCode::Down => {
                                 let filtered_len = interface_name_vec
                                     .iter()
-                                    .filter(|&name| name.contains(&*filter))
+                                    .filter(|&name| {
+                                        interface_matches(&self.veth_peers, name, filter)
+                                    })
                                     .count();
                                 if *index + 1 < filtered_len {
                                     *index += 1;
@@ -419,11 +1647,13 @@ impl App {
                             KeyCode::Enter => {
                                 let name_match: Vec<_> = interface_name_vec
                                     .iter()
-                                    .filter(|&name| name.contains(&*filter))
+                                    .filter(|&name| {
+                                        interface_matches(&self.veth_peers, name, filter)
+                                    })
                                     .collect();
 
                                 if let Some(&selected_interface) = name_match.get(*index) {
-                                    if *selected_interface == "all".to_string() {
+                                    if *selected_interface == "all" {
                                         self.selected_interface = InterfaceSelected::All;
                                         self.mode = Mode::Normal;
                                     } else {
@@ -440,12 +1670,11 @@ impl App {
                             }
                             _ => {}
                         },
-                        Mode::FilterLocalAddress { filter, index } => match key.code {
+                        Mode::FilterNeighbors { filter, index } => match key.code {
                             KeyCode::Char('w') if key.modifiers.contains(KeyModifiers::CONTROL) => {
                                 filter.clear();
                                 *index = 0;
                             }
-
                             KeyCode::Char(c) => {
                                 filter.push(c);
                                 *index = 0;
@@ -454,37 +1683,118 @@ impl App {
                                 filter.pop();
                                 *index = 0;
                             }
-                            KeyCode::Up => {
-                                if *index > 0 {
-                                    *index -= 1;
-                                }
+                            KeyCode::Up if *index > 0 => {
+                                *index -= 1;
                             }
                             KeyCode::Down => {
                                 let hostname_cache = self.hostname_cache_arc.lock().unwrap();
                                 let filtered_count = self
-                                    .tcp_stats
-                                    .clone()
-                                    .unwrap()
+                                    .neighbors
                                     .iter()
-                                    .filter(|conn| {
-                                        tcp_matches_filter(conn, filter, &hostname_cache)
+                                    .filter(|n| {
+                                        neighbor_matches_filter(
+                                            n,
+                                            &self.mac_changes,
+                                            filter,
+                                            &hostname_cache,
+                                        )
                                     })
                                     .count();
+                                if *index + 1 < filtered_count {
+                                    *index += 1;
+                                }
+                            }
+                            KeyCode::Esc => {
+                                self.mode = Mode::Normal;
+                            }
+                            _ => {}
+                        },
+                        Mode::FilterConntrack { filter, index } => match key.code {
+                            KeyCode::Char('w') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+                                filter.clear();
+                                *index = 0;
+                            }
+                            KeyCode::Char(c) => {
+                                filter.push(c);
+                                *index = 0;
+                            }
+                            KeyCode::Backspace => {
+                                filter.pop();
+                                *index = 0;
+                            }
+                            KeyCode::Up if *index > 0 => {
+                                *index -= 1;
+                            }
+                            KeyCode::Down => {
+                                let filtered_count = self
+                                    .conntrack
+                                    .iter()
+                                    .filter(|e| conntrack_matches_filter(e, filter))
+                                    .count();
+                                if *index + 1 < filtered_count {
+                                    *index += 1;
+                                }
+                            }
+                            KeyCode::Esc => {
+                                self.mode = Mode::Normal;
+                            }
+                            _ => {}
+                        },
+                        Mode::FilterLocalAddress { filter, index } => match key.code {
+                            KeyCode::Char('w') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+                                filter.clear();
+                                *index = 0;
+                            }
+                            KeyCode::Char('g') if self.locked.is_some() => {
+                                self.jump_to_egress();
+                            }
+
+                            KeyCode::Char(c) => {
+                                filter.push(c);
+                                *index = 0;
+                            }
+                            KeyCode::Backspace => {
+                                filter.pop();
+                                *index = 0;
+                            }
+                            KeyCode::Up if *index > 0 => {
+                                *index -= 1;
+                            }
+                            KeyCode::Down => {
+                                let hostname_cache = self.hostname_cache_arc.lock().unwrap();
+                                let process_cache = self.process_cache_arc.lock().unwrap();
+                                let filtered_count = collect_sockets(
+                                    self.socket_view,
+                                    self.tcp_stats.as_deref().unwrap_or_default(),
+                                    self.udp_stats.as_deref().unwrap_or_default(),
+                                )
+                                .into_iter()
+                                .filter(|conn| {
+                                    socket_matches_filter(
+                                        *conn,
+                                        filter,
+                                        &hostname_cache,
+                                        &process_cache,
+                                    )
+                                })
+                                .count();
 
                                 if *index + 1 < filtered_count {
                                     *index += 1;
                                 }
                             }
                             KeyCode::Enter => {
-                                if self.selected_index.is_none() {
-                                    self.selected_index = Some(*index);
+                                if self.locked.is_none() {
+                                    let (filter, index) = (filter.clone(), *index);
+                                    self.lock_socket(&filter, index);
                                 } else {
-                                    self.selected_index = None;
+                                    self.locked = None;
                                 }
+                                self.refresh_tcp_details();
                             }
                             KeyCode::Esc => {
-                                if self.selected_index.is_some() {
-                                    self.selected_index = None;
+                                if self.locked.is_some() {
+                                    self.locked = None;
                                 } else {
                                     self.mode = Mode::Normal;
                                 }
@@ -492,13 +1802,8 @@ impl App {
                             _ => {}
                         },
                     }
-                    if self.show_help {
-                        match key.code {
-                            KeyCode::Esc => {
-                                self.show_help = false;
-                            }
-                            _ => {}
-                        }
+                    if self.show_help && key.code == KeyCode::Esc {
+                        self.show_help = false;
                     }
                     if self.enter_tick_active {
                         match key.code {
@@ -532,14 +1837,10 @@ impl App {
                                 if let Some(ref interface) = self.editing_interface {
                                     if let Ok(rx) = self.speed_input.parse::<f64>() {
                                         if rx > 0.0 {
-                                            let tx = self
-                                                .interface_speeds
-                                                .get(interface)
-                                                .map(|(_, tx)| *tx)
-                                                .unwrap_or(rx);
-
                                             self.interface_speeds
-                                                .insert(interface.clone(), (rx, tx));
+                                                .entry(interface.clone())
+                                                .or_default()
+                                                .0 = Some(rx);
                                             let _ = save_interface_speeds(&self.interface_speeds);
                                         }
                                     }
@@ -567,14 +1868,10 @@ impl App {
                                 if let Some(ref interface) = self.editing_interface {
                                     if let Ok(tx) = self.speed_input.parse::<f64>() {
                                         if tx > 0.0 {
-                                            let rx = self
-                                                .interface_speeds
-                                                .get(interface)
-                                                .map(|(rx, _)| *rx)
-                                                .unwrap_or(tx);
-
                                             self.interface_speeds
-                                                .insert(interface.clone(), (rx, tx));
+                                                .entry(interface.clone())
+                                                .or_default()
+                                                .1 = Some(tx);
                                             let _ = save_interface_speeds(&self.interface_speeds);
                                         }
                                     }
@@ -593,20 +1890,19 @@ impl App {
             }
 
             if last_tick.elapsed() >= self.tick_rate {
-                self.update_avg = true;
+                // The cursor stays on its interface while others come and go around it.
+                let cursor = self.interface_names().remove(self.vertical_scroll);
                 self.get_stuff()?;
 
-                let rx: f64 = self
-                    .rx_data
-                    .values()
-                    .map(|v| v.last().map(|(_, b)| *b).unwrap_or(0.0))
-                    .sum();
+                interface_name_vec = self.interface_names();
+                let new_len = interface_name_vec.len();
+                self.vertical_scroll = cursor
+                    .and_then(|name| interface_name_vec.iter().position(|n| *n == name))
+                    .unwrap_or(self.vertical_scroll.min(new_len - 1));
+                self.vertical_scroll_state = self.vertical_scroll_state.content_length(new_len);
 
-                let tx: f64 = self
-                    .tx_data
-                    .values()
-                    .map(|v| v.last().map(|(_, b)| *b).unwrap_or(0.0))
-                    .sum();
+                let rx = tick_total(&self.rx_data, self.window[1]);
+                let tx = tick_total(&self.tx_data, self.window[1]);
 
                 self.push_total_vals(rx, tx);
                 last_tick = Instant::now();
@@ -615,17 +1911,40 @@ impl App {
         Ok(())
     }
 
-    pub fn get_rx_limit(&self, interface: &str) -> f64 {
-        self.interface_speeds
+    // RX/TX link speed in Mbps; speeds from the config file win over what the driver reports,
+    // per direction. A direction known from neither borrows the other one.
+    pub fn link_speed(&self, interface: &str) -> (Option<(f64, f64)>, SpeedSource) {
+        let (rx, tx) = self
+            .interface_speeds
             .get(interface)
-            .map(|(rx, _)| *rx / 8.0)
+            .copied()
+            .unwrap_or_default();
+        let detected = self.detected_speeds.get(interface).map(|d| d.mbps);
+        let source = if rx.is_some() || tx.is_some() {
+            SpeedSource::Configured
+        } else if detected.is_some() {
+            SpeedSource::Detected
+        } else {
+            SpeedSource::Unknown
+        };
+        match (rx.or(detected), tx.or(detected)) {
+            (Some(rx), Some(tx)) => (Some((rx, tx)), source),
+            (Some(speed), None) | (None, Some(speed)) => (Some((speed, speed)), source),
+            (None, None) => (None, source),
+        }
+    }
+
+    pub fn get_rx_limit(&self, interface: &str) -> f64 {
+        self.link_speed(interface)
+            .0
+            .map(|(rx, _)| rx / 8.0)
             .unwrap_or(100.0)
     }
 
     pub fn get_tx_limit(&self, interface: &str) -> f64 {
-        self.interface_speeds
-            .get(interface)
-            .map(|(_, tx)| *tx / 8.0)
+        self.link_speed(interface)
+            .0
+            .map(|(_, tx)| tx / 8.0)
             .unwrap_or(100.0)
     }
 
@@ -635,11 +1954,17 @@ impl App {
     }
 
     pub fn tcp_tablescroll_up(&mut self) {
+        if self.panel == Panel::Processes {
+            return self.move_process_cursor(false);
+        }
         self.tcp_vertical_scroll = self.tcp_vertical_scroll.saturating_sub(1);
         self.tcp_update_scroll_state();
     }
 
     pub fn tcp_tablescroll_down(&mut self) {
+        if self.panel == Panel::Processes {
+            return self.move_process_cursor(true);
+        }
         self.tcp_vertical_scroll = self.tcp_vertical_scroll.saturating_add(1);
         self.tcp_update_scroll_state();
     }
@@ -675,22 +2000,137 @@ impl App {
     pub fn render(
         &mut self,
         frame: &mut Frame,
-        net_data: &Vec<NetworkStats>,
-        tcp_data: &Vec<TcpStats>,
-        rx_peak_speed: &mut HashMap<String, f64>,
-        tx_peak_speed: &mut HashMap<String, f64>,
-        rx_avg_speed: &mut HashMap<String, f64>,
-        tx_avg_speed: &mut HashMap<String, f64>,
+        net_data: &[NetworkStats],
+        tcp_data: &[TcpStats],
+        udp_data: &[UdpStats],
     ) {
-        crate::ui::draw_interface_mode(
-            self,
-            frame,
-            net_data,
-            tcp_data,
-            rx_peak_speed,
-            tx_peak_speed,
-            rx_avg_speed,
-            tx_avg_speed,
+        crate::ui::draw_interface_mode(self, frame, net_data, tcp_data, udp_data);
+    }
+}
+
+// Same name is not enough: a deleted and re-added veth restarts its counters under a new ifindex.
+fn same_link(prev: &NetworkStats, new: &NetworkStats) -> bool {
+    match (&prev.link, &new.link) {
+        (Some(p), Some(n)) => p.ifindex == n.ifindex,
+        _ => true,
+    }
+}
+
+#[derive(Debug, PartialEq)]
+enum CounterDelta {
+    Bytes(u64),
+    Wrapped,
+    Reset,
+}
+
+// A counter going backwards is either a 32-bit counter (old drivers, /proc/net/dev on 32-bit
+// kernels) rolling over near its limit, or the device being reset. Neither yields a trustworthy
+// rate for this tick.
+fn counter_delta(prev: u64, new: u64) -> CounterDelta {
+    const U32_RANGE: u64 = 1 << 32;
+    if new >= prev {
+        CounterDelta::Bytes(new - prev)
+    } else if prev < U32_RANGE && prev > U32_RANGE / 2 && new < U32_RANGE / 2 {
+        CounterDelta::Wrapped
+    } else {
+        CounterDelta::Reset
+    }
+}
+
+// Sum of the samples taken at `now`, or `None` if any of them is a discontinuity.
+fn tick_total(data: &HashMap<String, Vec<Sample>>, now: f64) -> Option<f64> {
+    data.values()
+        .filter_map(|v| v.last().filter(|(t, _)| *t == now))
+        .map(|(_, bytes)| *bytes)
+        .sum()
+}
+
+pub fn filter_netns<'a>(namespaces: &'a [NetNs], filter: &str) -> Vec<&'a NetNs> {
+    let filter = filter.to_lowercase();
+    namespaces
+        .iter()
+        .filter(|ns| ns.name.to_lowercase().contains(&filter))
+        .collect()
+}
+
+// The interface filter matches names, and for veths the container or namespace behind them.
+pub fn interface_matches(peers: &HashMap<String, VethPeer>, name: &str, filter: &str) -> bool {
+    if name.contains(filter) {
+        return true;
+    }
+    let Some(peer) = peers.get(name) else {
+        return false;
+    };
+    let filter = filter.to_lowercase();
+    [
+        Some(peer.label()),
+        Some(peer.netns.clone()),
+        peer.container.as_ref().map(|c| c.runtime.to_string()),
+        peer.container.as_ref().map(|c| c.id.clone()),
+    ]
+    .into_iter()
+    .flatten()
+    .any(|field| field.to_lowercase().contains(&filter))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn locked_socket_follows_its_key() {
+        let sockets = parse_tcp_table(include_str!("../tests/fixtures/proc_net_tcp")).unwrap();
+        let mut locked = LockedSocket::new(sockets[2].socket_key(), &sockets, &[]).unwrap();
+
+        // Sockets ahead of it close and the rest come back in another order.
+        let mut next = vec![sockets[3].clone(), sockets[2].clone()];
+        next[1].tx_queue = 42;
+        locked.follow(&next, &[]);
+        assert!(!locked.closed);
+        assert_eq!(locked.last.socket().socket_key(), sockets[2].socket_key());
+        assert_eq!(locked.last.socket().tx_queue(), 42);
+
+        // A new socket on the same flow is not the locked one.
+        next[1].inode += 1;
+        next[1].tx_queue = 0;
+        locked.follow(&next, &[]);
+        assert!(locked.closed);
+        assert_eq!(locked.last.socket().tx_queue(), 42);
+    }
+
+    #[test]
+    fn counter_delta_classifies_samples() {
+        assert_eq!(counter_delta(1_000, 1_500), CounterDelta::Bytes(500));
+        assert_eq!(counter_delta(1_000, 1_000), CounterDelta::Bytes(0));
+        // A 32-bit counter rolling over just below its limit.
+        assert_eq!(
+            counter_delta(u32::MAX as u64 - 10, 20),
+            CounterDelta::Wrapped
         );
+        // Back to a small value from well within 32 bits: the device was reset.
+        assert_eq!(counter_delta(1_000_000, 50), CounterDelta::Reset);
+        // 64-bit counters never wrap in practice, so going backwards is a reset too.
+        assert_eq!(counter_delta(1 << 40, (1 << 40) - 1), CounterDelta::Reset);
+        assert_eq!(counter_delta(1 << 40, 20), CounterDelta::Reset);
+    }
+
+    #[test]
+    fn same_link_compares_ifindex() {
+        let stats = |ifindex: Option<u32>| NetworkStats {
+            name: "veth0".to_string(),
+            receive: Receive::default(),
+            transmit: Transmit::default(),
+            link: ifindex.map(|ifindex| LinkInfo {
+                ifindex,
+                ..Default::default()
+            }),
+        };
+
+        assert!(same_link(&stats(Some(7)), &stats(Some(7))));
+        // Deleted and re-added under the same name.
+        assert!(!same_link(&stats(Some(7)), &stats(Some(12))));
+        // /proc/net/dev carries no ifindex, so the name has to do.
+        assert!(same_link(&stats(None), &stats(None)));
+        assert!(same_link(&stats(Some(7)), &stats(None)));
     }
 }
@@ -1,18 +1,38 @@
 use crate::app::App;
 use anyhow::{Ok, Result};
+use clap::{Arg, Command};
+use netns::resolve_netns;
 use parser::*;
 
 mod app;
 mod models;
+mod netlink;
+mod netns;
 mod parser;
 mod theme;
 mod ui;
 
 fn main() -> Result<()> {
+    let matches = Command::new("rx-tx")
+        .version(env!("CARGO_PKG_VERSION"))
+        .about("Terminal bandwidth and connection monitor")
+        .arg(
+            Arg::new("netns")
+                .long("netns")
+                .value_name("NAME|PID|PATH")
+                .help("Monitor another network namespace: a name under /var/run/netns, a PID or an nsfs path"),
+        )
+        .get_matches();
+
     initialize_conf()?;
-    let mut terminal = ratatui::init();
     let mut app = App::default();
-    app.get_stuff()?;
+    if let Some(netns) = matches.get_one::<String>("netns") {
+        app.switch_netns(resolve_netns(netns)?)?;
+    } else {
+        app.get_stuff()?;
+    }
+
+    let mut terminal = ratatui::init();
     app.run(&mut terminal)?;
 
     ratatui::restore();
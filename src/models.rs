@@ -1,13 +1,66 @@
 use crate::app::{App, ByteUnit};
+use crate::parser::{tcp_state_name, udp_state_name};
+use std::collections::HashMap;
+use std::net::IpAddr;
+use std::path::PathBuf;
+use std::time::Instant;
 
 #[derive(Debug, Clone)]
 pub struct NetworkStats {
     pub name: String,
     pub receive: Receive,
     pub transmit: Transmit,
+    pub link: Option<LinkInfo>,
 }
 
+// Bytes moved during one tick, stamped with the tick time. `None` marks a counter reset or
+// wraparound: the tick is kept so graphs can show the gap, but it has no rate.
+pub type Sample = (f64, Option<f64>);
+
+// Negotiated link speed as reported by the driver, in Mbps.
 #[derive(Debug, Clone)]
+pub struct LinkSpeed {
+    pub mbps: f64,
+    pub duplex: Option<String>,
+    pub wireless: bool,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum SpeedSource {
+    Configured,
+    Detected,
+    Unknown,
+}
+
+impl SpeedSource {
+    pub fn name(&self) -> &'static str {
+        match self {
+            SpeedSource::Configured => "configured",
+            SpeedSource::Detected => "detected",
+            SpeedSource::Unknown => "unknown",
+        }
+    }
+}
+
+// Link metadata only rtnetlink provides; `None` when stats came from /proc/net/dev.
+#[derive(Debug, Clone, Default)]
+pub struct LinkInfo {
+    pub ifindex: u32,
+    pub operstate: u8,
+    pub mtu: u32,
+    pub mac: Option<String>,
+    pub master: Option<u32>,
+    pub link: Option<u32>,
+    // IFLA_LINK_NETNSID: the namespace `link` lives in, as an id of the current namespace.
+    pub link_netnsid: Option<i32>,
+    // IFLA_INFO_KIND, e.g. "veth", "bridge", "bond"; absent for physical devices.
+    pub kind: Option<String>,
+}
+
+// `drop`, `frame` and `carrier` are the sums /proc/net/dev shows. The per-cause counters behind
+// them come from rtnetlink or /sys/class/net/<if>/statistics and are `None` when neither was
+// available.
+#[derive(Debug, Clone, Default)]
 pub struct Receive {
     pub bytes: u64,
     pub packets: u64,
@@ -17,9 +70,16 @@ pub struct Receive {
     pub frame: u64,
     pub compressed: u64,
     pub multicast: u64,
+    pub dropped: Option<u64>,
+    pub missed_errors: Option<u64>,
+    pub length_errors: Option<u64>,
+    pub over_errors: Option<u64>,
+    pub crc_errors: Option<u64>,
+    pub frame_errors: Option<u64>,
+    pub nohandler: Option<u64>,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Default)]
 pub struct Transmit {
     pub bytes: u64,
     pub packets: u64,
@@ -29,9 +89,33 @@ pub struct Transmit {
     pub colls: u64,
     pub carrier: u64,
     pub compressed: u64,
+    pub aborted_errors: Option<u64>,
+    pub carrier_errors: Option<u64>,
+    pub heartbeat_errors: Option<u64>,
+    pub window_errors: Option<u64>,
 }
 
 impl Receive {
+    // Every counter but bytes and packets, in display order; breakdowns are indented under
+    // the sum they make up.
+    pub fn counters(&self) -> Vec<(&'static str, Option<u64>)> {
+        vec![
+            ("Errors", Some(self.errs)),
+            ("Drops", Some(self.drop)),
+            ("  dropped", self.dropped),
+            ("  missed", self.missed_errors),
+            ("FIFO", Some(self.fifo)),
+            ("Frame", Some(self.frame)),
+            ("  length", self.length_errors),
+            ("  overrun", self.over_errors),
+            ("  crc", self.crc_errors),
+            ("  frame", self.frame_errors),
+            ("No handler", self.nohandler),
+            ("Compressed", Some(self.compressed)),
+            ("Multicast", Some(self.multicast)),
+        ]
+    }
+
     pub fn display(&self, app: &mut App, total: Option<u64>) -> String {
         let raw_bytes = app.raw_bytes;
         if raw_bytes {
@@ -51,6 +135,21 @@ impl Receive {
 }
 
 impl Transmit {
+    pub fn counters(&self) -> Vec<(&'static str, Option<u64>)> {
+        vec![
+            ("Errors", Some(self.errs)),
+            ("Drops", Some(self.drop)),
+            ("FIFO", Some(self.fifo)),
+            ("Collisions", Some(self.colls)),
+            ("Carrier", Some(self.carrier)),
+            ("  aborted", self.aborted_errors),
+            ("  carrier", self.carrier_errors),
+            ("  heartbeat", self.heartbeat_errors),
+            ("  window", self.window_errors),
+            ("Compressed", Some(self.compressed)),
+        ]
+    }
+
     pub fn display(&self, app: &mut App, total: Option<u64>) -> String {
         let raw_bytes = app.raw_bytes;
         if raw_bytes {
@@ -109,13 +208,697 @@ pub fn format_bytes(data: u64, unit: &ByteUnit) -> String {
 
 #[derive(Clone)]
 pub struct TcpStats {
-    pub local_ip: [u8; 4],
+    pub local_ip: IpAddr,
     pub local_port: u16,
-    pub remote_ip: [u8; 4],
+    pub remote_ip: IpAddr,
     pub remote_port: u16,
     pub state: u64,
     pub tx_queue: u64,
     pub rx_queue: u64,
     pub uid: u32,
     pub inode: u64,
+    pub timer: TcpTimer,
+    pub tcp_info: Option<TcpInfo>,
+}
+
+// The `tr tm->when retrnsmt timeout` columns of /proc/net/tcp: which kernel timer is pending
+// and when it fires, unrecovered RTO retransmits, and unanswered keepalive or zero window
+// probes.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct TcpTimer {
+    pub kind: u8,
+    pub expires_ms: u64,
+    pub retransmits: u32,
+    pub probes: u32,
+}
+
+impl TcpTimer {
+    // Same names `ss -o` uses.
+    pub fn name(&self) -> &'static str {
+        match self.kind {
+            0 => "off",
+            1 => "on",
+            2 => "keepalive",
+            3 => "timewait",
+            4 => "persist",
+            _ => "unknown",
+        }
+    }
+}
+
+// Subset of the kernel's `struct tcp_info`, only available from the netlink backend.
+#[derive(Debug, Clone, Default)]
+pub struct TcpInfo {
+    pub ca_state: u8,
+    pub retransmits: u8,
+    pub probes: u8,
+    pub backoff: u8,
+    pub rto_us: u32,
+    pub snd_mss: u32,
+    pub unacked: u32,
+    pub lost: u32,
+    pub retrans: u32,
+    pub rtt_us: u32,
+    pub rttvar_us: u32,
+    pub min_rtt_us: u32,
+    pub snd_ssthresh: u32,
+    pub snd_cwnd: u32,
+    pub total_retrans: u32,
+    pub pacing_rate: u64,
+    pub delivery_rate: u64,
+    pub bytes_acked: u64,
+    pub bytes_received: u64,
+    pub bytes_retrans: u64,
+}
+
+impl TcpInfo {
+    pub fn ca_state_name(&self) -> &'static str {
+        match self.ca_state {
+            0 => "Open",
+            1 => "Disorder",
+            2 => "CWR",
+            3 => "Recovery",
+            4 => "Loss",
+            _ => "unknown",
+        }
+    }
+}
+
+// Socket memory from INET_DIAG_SKMEMINFO, the `skmem:(...)` of `ss -m`.
+#[derive(Debug, Clone, Default)]
+pub struct SkMemInfo {
+    pub rmem_alloc: u32,
+    pub rcvbuf: u32,
+    pub wmem_alloc: u32,
+    pub sndbuf: u32,
+    pub fwd_alloc: u32,
+    pub wmem_queued: u32,
+    pub optmem: u32,
+    pub backlog: u32,
+    pub drops: u32,
+}
+
+// What inet_diag reports about a single TCP socket, queried for the locked connection only.
+#[derive(Debug, Clone, Default)]
+pub struct TcpDetails {
+    pub info: Option<TcpInfo>,
+    pub congestion: Option<String>,
+    pub skmem: Option<SkMemInfo>,
+}
+
+#[derive(Clone)]
+pub struct UdpStats {
+    pub local_ip: IpAddr,
+    pub local_port: u16,
+    pub remote_ip: IpAddr,
+    pub remote_port: u16,
+    pub state: u64,
+    pub tx_queue: u64,
+    pub rx_queue: u64,
+    pub uid: u32,
+    pub inode: u64,
+    pub drops: u64,
+}
+
+#[derive(Debug, Clone)]
+pub struct ProcessInfo {
+    pub pid: u32,
+    pub comm: String,
+    pub cmdline: String,
+}
+
+impl ProcessInfo {
+    pub fn label(&self) -> String {
+        format!("{}/{}", self.comm, self.pid)
+    }
+}
+
+// What one socket moved between two samples of its cumulative byte counters.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct FlowDelta {
+    pub rx_bytes: u64,
+    pub tx_bytes: u64,
+    pub rx_rate: f64,
+    pub tx_rate: f64,
+}
+
+// TCP traffic of one process, summed over the tcp_info byte deltas of the sockets it holds.
+// Rates and connections are for the last tick, bytes since rx-tx started.
+#[derive(Debug, Clone, Default)]
+pub struct ProcessTraffic {
+    pub pid: u32,
+    pub comm: String,
+    pub uid: u32,
+    pub rx_rate: f64,
+    pub tx_rate: f64,
+    pub rx_bytes: u64,
+    pub tx_bytes: u64,
+    pub connections: usize,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Protocol {
+    Tcp,
+    Udp,
+}
+
+impl Protocol {
+    pub fn name(&self) -> &'static str {
+        match self {
+            Protocol::Tcp => "TCP",
+            Protocol::Udp => "UDP",
+        }
+    }
+}
+
+// Local and remote endpoints of a socket, which identify a flow across ticks.
+pub type FlowKey = (IpAddr, u16, IpAddr, u16);
+
+// A flow plus the socket behind it. A TIME_WAIT socket or a reused port has a different inode,
+// so it never stands in for the socket that was there before.
+pub type SocketKey = (Protocol, FlowKey, u64);
+
+// A connection over its whole life. Unlike SocketKey it ignores the inode, which drops to 0 once
+// the owner closes the socket and the kernel finishes FIN_WAIT/TIME_WAIT on its own.
+pub type ConnectionKey = (Protocol, FlowKey);
+
+// What happened to one connection between two socket snapshots.
+#[derive(Debug, Clone, PartialEq)]
+pub enum SocketEvent {
+    Opened(ConnectionKey),
+    StateChanged(ConnectionKey, &'static str, &'static str),
+    Closed(ConnectionKey),
+}
+
+// A connection that has gone from the socket tables, as listed in the Recent panel. Connections
+// already open at startup have `first_seen` set to the first tick. The process is remembered from
+// while the socket still had an owner, since TIME_WAIT sockets don't.
+#[derive(Clone)]
+pub struct ClosedConnection {
+    pub last: SocketSnapshot,
+    pub process: Option<String>,
+    pub first_seen: Instant,
+    pub seen_at_start: bool,
+    pub last_seen: Instant,
+    pub closed_at: Instant,
+}
+
+// Common view over TCP and UDP sockets so the connections table can list either or both.
+pub trait Socket {
+    fn protocol(&self) -> Protocol;
+    fn local_ip(&self) -> IpAddr;
+    fn local_port(&self) -> u16;
+    fn remote_ip(&self) -> IpAddr;
+    fn remote_port(&self) -> u16;
+    fn state_name(&self) -> &'static str;
+    fn tx_queue(&self) -> u64;
+    fn rx_queue(&self) -> u64;
+    fn uid(&self) -> u32;
+    fn inode(&self) -> u64;
+    fn drops(&self) -> Option<u64>;
+    fn tcp_info(&self) -> Option<&TcpInfo>;
+
+    fn flow_key(&self) -> FlowKey {
+        (
+            self.local_ip(),
+            self.local_port(),
+            self.remote_ip(),
+            self.remote_port(),
+        )
+    }
+
+    fn socket_key(&self) -> SocketKey {
+        (self.protocol(), self.flow_key(), self.inode())
+    }
+
+    fn connection_key(&self) -> ConnectionKey {
+        (self.protocol(), self.flow_key())
+    }
+}
+
+// An owned copy of one socket, for when it has to outlive the tick it was read in.
+#[derive(Clone)]
+pub enum SocketSnapshot {
+    Tcp(TcpStats),
+    Udp(UdpStats),
+}
+
+impl SocketSnapshot {
+    // Owned copy of the socket with this key, if the tables still hold it.
+    pub fn find(key: &SocketKey, tcp: &[TcpStats], udp: &[UdpStats]) -> Option<SocketSnapshot> {
+        match key.0 {
+            Protocol::Tcp => tcp
+                .iter()
+                .find(|s| s.socket_key() == *key)
+                .cloned()
+                .map(SocketSnapshot::Tcp),
+            Protocol::Udp => udp
+                .iter()
+                .find(|s| s.socket_key() == *key)
+                .cloned()
+                .map(SocketSnapshot::Udp),
+        }
+    }
+
+    pub fn socket(&self) -> &dyn Socket {
+        match self {
+            SocketSnapshot::Tcp(s) => s,
+            SocketSnapshot::Udp(s) => s,
+        }
+    }
+}
+
+impl Socket for TcpStats {
+    fn protocol(&self) -> Protocol {
+        Protocol::Tcp
+    }
+    fn local_ip(&self) -> IpAddr {
+        self.local_ip
+    }
+    fn local_port(&self) -> u16 {
+        self.local_port
+    }
+    fn remote_ip(&self) -> IpAddr {
+        self.remote_ip
+    }
+    fn remote_port(&self) -> u16 {
+        self.remote_port
+    }
+    fn state_name(&self) -> &'static str {
+        tcp_state_name(self.state)
+    }
+    fn tx_queue(&self) -> u64 {
+        self.tx_queue
+    }
+    fn rx_queue(&self) -> u64 {
+        self.rx_queue
+    }
+    fn uid(&self) -> u32 {
+        self.uid
+    }
+    fn inode(&self) -> u64 {
+        self.inode
+    }
+    fn drops(&self) -> Option<u64> {
+        None
+    }
+    fn tcp_info(&self) -> Option<&TcpInfo> {
+        self.tcp_info.as_ref()
+    }
+}
+
+impl Socket for UdpStats {
+    fn protocol(&self) -> Protocol {
+        Protocol::Udp
+    }
+    fn local_ip(&self) -> IpAddr {
+        self.local_ip
+    }
+    fn local_port(&self) -> u16 {
+        self.local_port
+    }
+    fn remote_ip(&self) -> IpAddr {
+        self.remote_ip
+    }
+    fn remote_port(&self) -> u16 {
+        self.remote_port
+    }
+    fn state_name(&self) -> &'static str {
+        udp_state_name(self.state)
+    }
+    fn tx_queue(&self) -> u64 {
+        self.tx_queue
+    }
+    fn rx_queue(&self) -> u64 {
+        self.rx_queue
+    }
+    fn uid(&self) -> u32 {
+        self.uid
+    }
+    fn inode(&self) -> u64 {
+        self.inode
+    }
+    fn drops(&self) -> Option<u64> {
+        Some(self.drops)
+    }
+    fn tcp_info(&self) -> Option<&TcpInfo> {
+        None
+    }
+}
+
+// Declares a counter block from /proc/net/snmp or /proc/net/netstat: one field per kernel column
+// we care about, plus the column names so the panel can list them without repeating itself.
+macro_rules! proto_counters {
+    ($name:ident { $($field:ident => $key:literal),* $(,)? }) => {
+        #[derive(Debug, Clone, Default)]
+        pub struct $name {
+            $(pub $field: u64,)*
+        }
+
+        impl $name {
+            pub fn from_map(map: &HashMap<String, u64>) -> Self {
+                Self {
+                    $($field: map.get($key).copied().unwrap_or(0),)*
+                }
+            }
+
+            pub fn fields(&self) -> Vec<(&'static str, u64)> {
+                vec![$(($key, self.$field)),*]
+            }
+        }
+    };
+}
+
+proto_counters!(IpCounters {
+    in_receives => "InReceives",
+    in_hdr_errors => "InHdrErrors",
+    in_addr_errors => "InAddrErrors",
+    forw_datagrams => "ForwDatagrams",
+    in_discards => "InDiscards",
+    in_delivers => "InDelivers",
+    out_requests => "OutRequests",
+    out_discards => "OutDiscards",
+    out_no_routes => "OutNoRoutes",
+    reasm_reqds => "ReasmReqds",
+    reasm_oks => "ReasmOKs",
+    reasm_fails => "ReasmFails",
+    frag_oks => "FragOKs",
+    frag_fails => "FragFails",
+    frag_creates => "FragCreates",
+});
+
+proto_counters!(IcmpCounters {
+    in_msgs => "InMsgs",
+    in_errors => "InErrors",
+    in_dest_unreachs => "InDestUnreachs",
+    out_msgs => "OutMsgs",
+    out_errors => "OutErrors",
+    out_dest_unreachs => "OutDestUnreachs",
+});
+
+proto_counters!(TcpCounters {
+    active_opens => "ActiveOpens",
+    passive_opens => "PassiveOpens",
+    attempt_fails => "AttemptFails",
+    estab_resets => "EstabResets",
+    curr_estab => "CurrEstab",
+    in_segs => "InSegs",
+    out_segs => "OutSegs",
+    retrans_segs => "RetransSegs",
+    in_errs => "InErrs",
+    out_rsts => "OutRsts",
+});
+
+proto_counters!(UdpCounters {
+    in_datagrams => "InDatagrams",
+    no_ports => "NoPorts",
+    in_errors => "InErrors",
+    out_datagrams => "OutDatagrams",
+    rcvbuf_errors => "RcvbufErrors",
+    sndbuf_errors => "SndbufErrors",
+    in_csum_errors => "InCsumErrors",
+});
+
+proto_counters!(TcpExtCounters {
+    syncookies_sent => "SyncookiesSent",
+    syncookies_recv => "SyncookiesRecv",
+    syncookies_failed => "SyncookiesFailed",
+    listen_overflows => "ListenOverflows",
+    listen_drops => "ListenDrops",
+    tcp_timeouts => "TCPTimeouts",
+    tcp_lost_retransmit => "TCPLostRetransmit",
+    tcp_fast_retrans => "TCPFastRetrans",
+    tcp_slow_start_retrans => "TCPSlowStartRetrans",
+    tcp_syn_retrans => "TCPSynRetrans",
+    tcp_abort_on_memory => "TCPAbortOnMemory",
+    tcp_abort_on_timeout => "TCPAbortOnTimeout",
+    tcp_backlog_drop => "TCPBacklogDrop",
+    prune_called => "PruneCalled",
+});
+
+proto_counters!(IpExtCounters {
+    in_no_routes => "InNoRoutes",
+    in_truncated_pkts => "InTruncatedPkts",
+    in_csum_errors => "InCsumErrors",
+    in_octets => "InOctets",
+    out_octets => "OutOctets",
+});
+
+#[derive(Debug, Clone)]
+pub struct NetNs {
+    pub name: String,
+    pub path: PathBuf,
+    pub inode: u64,
+    pub processes: usize,
+}
+
+// A container runtime recognised from a process's cgroup path.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Container {
+    pub runtime: &'static str,
+    pub id: String,
+    pub name: Option<String>,
+}
+
+impl Container {
+    // Runtime-assigned name when we could look it up, else the short id `docker ps` shows.
+    pub fn label(&self) -> String {
+        match &self.name {
+            Some(name) => name.clone(),
+            None => self.id.chars().take(12).collect(),
+        }
+    }
+}
+
+// A veth in the current namespace, as matched against the interfaces of other namespaces.
+// `netnsid` is unknown when links come from sysfs.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub struct VethLink {
+    pub name: String,
+    pub ifindex: u32,
+    pub iflink: u32,
+    pub netnsid: Option<i32>,
+}
+
+// Peers found by a background resolution, with the namespace inode and veths it ran for.
+pub type VethResolution = (u64, Vec<VethLink>, HashMap<String, VethPeer>);
+
+// The far end of a veth pair living in another namespace.
+#[derive(Debug, Clone)]
+pub struct VethPeer {
+    pub peer: String,
+    pub netns: String,
+    pub container: Option<Container>,
+}
+
+impl VethPeer {
+    // What the interface list shows: the container when known, otherwise the namespace.
+    pub fn label(&self) -> String {
+        match &self.container {
+            Some(container) => container.label(),
+            None => self.netns.clone(),
+        }
+    }
+}
+
+// One entry of /proc/net/route or /proc/net/ipv6_route. The kernel only exposes the main
+// table for IPv4 there; IPv6 also lists the local table.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Route {
+    pub destination: IpAddr,
+    pub prefix_len: u8,
+    pub gateway: Option<IpAddr>,
+    pub interface: String,
+    pub metric: u32,
+    pub flags: u32,
+}
+
+// One neighbour cache entry: IPv4 from /proc/net/arp, IPv6 from rtnetlink.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Neighbor {
+    pub ip: IpAddr,
+    pub mac: Option<String>,
+    pub device: String,
+    pub flags: String,
+    pub state: &'static str,
+    pub incomplete: bool,
+}
+
+// One direction of a conntrack entry. Ports are None for ICMP and other portless protocols.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ConntrackTuple {
+    pub src: IpAddr,
+    pub dst: IpAddr,
+    pub sport: Option<u16>,
+    pub dport: Option<u16>,
+}
+
+// One netfilter conntrack entry, from /proc/net/nf_conntrack or a ctnetlink dump. Packet and
+// byte counters are only there with net.netfilter.nf_conntrack_acct=1.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ConntrackEntry {
+    pub protocol: String,
+    pub state: Option<String>,
+    pub timeout: u64,
+    pub original: ConntrackTuple,
+    pub reply: ConntrackTuple,
+    pub orig_packets: Option<u64>,
+    pub orig_bytes: Option<u64>,
+    pub reply_packets: Option<u64>,
+    pub reply_bytes: Option<u64>,
+    pub assured: bool,
+    pub unreplied: bool,
+    pub mark: u32,
+}
+
+impl ConntrackEntry {
+    // The reply tuple differs from the swapped original one when the flow is NATed.
+    pub fn snat(&self) -> bool {
+        self.reply.dst != self.original.src || self.reply.dport != self.original.sport
+    }
+
+    pub fn dnat(&self) -> bool {
+        self.reply.src != self.original.dst || self.reply.sport != self.original.dport
+    }
+
+    pub fn packets(&self) -> Option<u64> {
+        Some(self.orig_packets? + self.reply_packets?)
+    }
+
+    pub fn bytes(&self) -> Option<u64> {
+        Some(self.orig_bytes? + self.reply_bytes?)
+    }
+}
+
+// A row of the interface tree: bridge ports, bond slaves and VLANs sit under their parent.
+#[derive(Debug, Clone)]
+pub struct InterfaceRow {
+    pub name: String,
+    pub depth: usize,
+    pub children: usize,
+}
+
+// One /proc/net/bonding/<bond> file.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct BondInfo {
+    pub mode: String,
+    pub mii_status: String,
+    pub active_slave: Option<String>,
+    pub slaves: Vec<BondSlave>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct BondSlave {
+    pub name: String,
+    pub mii_status: String,
+    pub speed: Option<String>,
+    pub link_failures: u64,
+}
+
+// One row of /proc/net/wireless. Link quality and signal/noise units are driver specific:
+// most report signal and noise in dBm, some an unsigned 0..=255 level.
+#[derive(Debug, Clone, PartialEq)]
+pub struct WirelessStats {
+    pub name: String,
+    pub status: u16,
+    pub link: f64,
+    pub level: f64,
+    // -256 in the file means the driver doesn't report noise.
+    pub noise: Option<f64>,
+    pub discarded_nwid: u64,
+    pub discarded_crypt: u64,
+    pub discarded_frag: u64,
+    pub discarded_retry: u64,
+    pub discarded_misc: u64,
+    pub missed_beacon: u64,
+}
+
+// One CPU's row of /proc/net/softnet_stat plus its NET_RX/NET_TX counts from /proc/softirqs.
+// Everything is a counter since boot except `backlog`, the current input queue length, which
+// kernels before 5.10 don't report.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct CpuNetStats {
+    pub cpu: usize,
+    pub processed: u64,
+    pub dropped: u64,
+    pub time_squeeze: u64,
+    pub received_rps: u64,
+    pub flow_limit: u64,
+    pub backlog: Option<u64>,
+    pub net_rx: u64,
+    pub net_tx: u64,
+}
+
+// One numbered line of /proc/interrupts. `counts` follows the CPU columns of the header, which
+// skip offline CPUs; `actions` are the handler names, usually "<device>-<queue>".
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct IrqStats {
+    pub irq: u32,
+    pub counts: Vec<u64>,
+    pub chip: String,
+    pub actions: String,
+}
+
+// An rx-N or tx-N directory under /sys/class/net/<if>/queues, with the per-queue counters the
+// driver reports through ethtool when it has any. `cpus` is the RPS or XPS mask, None when the
+// kernel doesn't offer it (XPS needs a multi-queue device).
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct NetQueue {
+    pub name: String,
+    pub cpus: Option<Vec<usize>>,
+    pub rps_flow_cnt: Option<u64>,
+    pub bql_inflight: Option<u64>,
+    pub bql_limit: Option<u64>,
+    pub tx_maxrate: Option<u64>,
+    pub counters: QueueCounters,
+}
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct QueueCounters {
+    pub packets: Option<u64>,
+    pub bytes: Option<u64>,
+    pub drops: Option<u64>,
+}
+
+// /proc/net/sockstat plus sockstat6. `inuse` counts are summed over both families; the rest
+// are shared by IPv4 and IPv6. Memory figures are in pages, except FRAG which is bytes.
+#[derive(Debug, Clone, Default)]
+pub struct SockStat {
+    pub tcp_inuse: u64,
+    pub tcp_orphan: u64,
+    pub tcp_tw: u64,
+    pub tcp_alloc: u64,
+    pub tcp_mem: u64,
+    pub udp_inuse: u64,
+    pub udp_mem: u64,
+    pub raw_inuse: u64,
+    pub frag_inuse: u64,
+    pub frag_memory: u64,
+    // net.ipv4.tcp_mem: min, pressure and max thresholds in pages.
+    pub tcp_mem_limits: Option<[u64; 3]>,
+}
+
+impl SockStat {
+    // TCP memory as a percentage of the tcp_mem max, and whether it reached the pressure
+    // threshold.
+    pub fn tcp_mem_usage(&self) -> Option<(f64, bool)> {
+        match self.tcp_mem_limits {
+            Some([_, pressure, max]) if max > 0 => Some((
+                self.tcp_mem as f64 / max as f64 * 100.0,
+                self.tcp_mem >= pressure,
+            )),
+            _ => None,
+        }
+    }
+}
+
+// What `netstat -s` shows, minus the long tail.
+#[derive(Debug, Clone, Default)]
+pub struct ProtoStats {
+    pub ip: IpCounters,
+    pub icmp: IcmpCounters,
+    pub tcp: TcpCounters,
+    pub udp: UdpCounters,
+    pub tcp_ext: TcpExtCounters,
+    pub ip_ext: IpExtCounters,
 }
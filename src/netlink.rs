@@ -0,0 +1,921 @@
+use crate::models::*;
+use crate::parser::{conntrack_tcp_state_name, neigh_state_name};
+use anyhow::{anyhow, Ok, Result};
+use std::fs::File;
+use std::io;
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+use std::os::fd::{AsRawFd, FromRawFd, OwnedFd};
+
+const NLMSG_HDRLEN: usize = 16;
+
+// inet_diag attribute types and the extension bits that request them (linux/inet_diag.h)
+const SOCK_DIAG_BY_FAMILY: u16 = 20;
+const INET_DIAG_INFO: u16 = 2;
+const INET_DIAG_CONG: u16 = 4;
+const INET_DIAG_SKMEMINFO: u16 = 7;
+const INET_DIAG_NOCOOKIE: u32 = !0;
+const TCP_LISTEN: u8 = 10;
+
+// A netlink socket that sends one dump request at a time and collects every reply.
+pub struct NetlinkSocket {
+    fd: OwnedFd,
+    seq: u32,
+}
+
+impl NetlinkSocket {
+    pub fn open(protocol: libc::c_int) -> Result<Self> {
+        let fd = unsafe {
+            libc::socket(
+                libc::AF_NETLINK,
+                libc::SOCK_RAW | libc::SOCK_CLOEXEC,
+                protocol,
+            )
+        };
+        if fd < 0 {
+            return Err(io::Error::last_os_error().into());
+        }
+        let fd = unsafe { OwnedFd::from_raw_fd(fd) };
+
+        let mut addr: libc::sockaddr_nl = unsafe { std::mem::zeroed() };
+        addr.nl_family = libc::AF_NETLINK as libc::sa_family_t;
+        let ret = unsafe {
+            libc::bind(
+                fd.as_raw_fd(),
+                &addr as *const libc::sockaddr_nl as *const libc::sockaddr,
+                std::mem::size_of::<libc::sockaddr_nl>() as libc::socklen_t,
+            )
+        };
+        if ret < 0 {
+            return Err(io::Error::last_os_error().into());
+        }
+
+        Ok(Self { fd, seq: 0 })
+    }
+
+    // Sends `payload` as a NLM_F_DUMP request and returns the payload of every reply
+    // message as (type, bytes), stopping at NLMSG_DONE.
+    pub fn dump(&mut self, msg_type: u16, payload: &[u8]) -> Result<Vec<(u16, Vec<u8>)>> {
+        self.seq = self.seq.wrapping_add(1);
+        let flags = (libc::NLM_F_REQUEST | libc::NLM_F_DUMP) as u16;
+        self.send(msg_type, flags, payload)?;
+        self.receive(true)
+    }
+
+    // Sends `payload` as a request for a single object and returns its one reply message.
+    pub fn get(&mut self, msg_type: u16, payload: &[u8]) -> Result<(u16, Vec<u8>)> {
+        self.seq = self.seq.wrapping_add(1);
+        self.send(msg_type, libc::NLM_F_REQUEST as u16, payload)?;
+        self.receive(false)?
+            .into_iter()
+            .next()
+            .ok_or(anyhow!("Empty netlink reply"))
+    }
+
+    fn receive(&mut self, dump: bool) -> Result<Vec<(u16, Vec<u8>)>> {
+        let mut messages = Vec::new();
+        let mut buf = vec![0u8; 64 * 1024];
+        loop {
+            let len = unsafe {
+                libc::recv(
+                    self.fd.as_raw_fd(),
+                    buf.as_mut_ptr() as *mut libc::c_void,
+                    buf.len(),
+                    0,
+                )
+            };
+            if len < 0 {
+                let err = io::Error::last_os_error();
+                if err.kind() == io::ErrorKind::Interrupted {
+                    continue;
+                }
+                return Err(err.into());
+            }
+
+            let mut data = &buf[..len as usize];
+            while data.len() >= NLMSG_HDRLEN {
+                let msg_len = read_u32(data, 0).unwrap_or(0) as usize;
+                let msg_kind = read_u16(data, 4).unwrap_or(0);
+                if msg_len < NLMSG_HDRLEN || msg_len > data.len() {
+                    return Err(anyhow!("Truncated netlink message"));
+                }
+
+                let body = &data[NLMSG_HDRLEN..msg_len];
+                match msg_kind as libc::c_int {
+                    libc::NLMSG_DONE => return Ok(messages),
+                    libc::NLMSG_ERROR => {
+                        let code = read_u32(body, 0).unwrap_or(0) as i32;
+                        if code != 0 {
+                            return Err(io::Error::from_raw_os_error(-code).into());
+                        }
+                    }
+                    _ => messages.push((msg_kind, body.to_vec())),
+                }
+
+                data = &data[align(msg_len).min(data.len())..];
+            }
+            if !dump && !messages.is_empty() {
+                return Ok(messages);
+            }
+        }
+    }
+
+    fn send(&self, msg_type: u16, flags: u16, payload: &[u8]) -> Result<()> {
+        let mut msg = Vec::with_capacity(NLMSG_HDRLEN + payload.len());
+        msg.extend_from_slice(&((NLMSG_HDRLEN + payload.len()) as u32).to_ne_bytes());
+        msg.extend_from_slice(&msg_type.to_ne_bytes());
+        msg.extend_from_slice(&flags.to_ne_bytes());
+        msg.extend_from_slice(&self.seq.to_ne_bytes());
+        msg.extend_from_slice(&0u32.to_ne_bytes());
+        msg.extend_from_slice(payload);
+
+        let ret = unsafe {
+            libc::send(
+                self.fd.as_raw_fd(),
+                msg.as_ptr() as *const libc::c_void,
+                msg.len(),
+                0,
+            )
+        };
+        if ret < 0 {
+            return Err(io::Error::last_os_error().into());
+        }
+        Ok(())
+    }
+}
+
+fn align(len: usize) -> usize {
+    (len + 3) & !3
+}
+
+pub fn read_u16(buf: &[u8], offset: usize) -> Option<u16> {
+    Some(u16::from_ne_bytes(
+        buf.get(offset..offset + 2)?.try_into().ok()?,
+    ))
+}
+
+pub fn read_u32(buf: &[u8], offset: usize) -> Option<u32> {
+    Some(u32::from_ne_bytes(
+        buf.get(offset..offset + 4)?.try_into().ok()?,
+    ))
+}
+
+pub fn read_u64(buf: &[u8], offset: usize) -> Option<u64> {
+    Some(u64::from_ne_bytes(
+        buf.get(offset..offset + 8)?.try_into().ok()?,
+    ))
+}
+
+// Splits a run of rtattr/nlattr TLVs into (type, payload), dropping the nested/byte-order flag bits.
+pub fn parse_attrs(mut buf: &[u8]) -> Vec<(u16, &[u8])> {
+    let mut attrs = Vec::new();
+    while buf.len() >= 4 {
+        let len = read_u16(buf, 0).unwrap_or(0) as usize;
+        let kind = read_u16(buf, 2).unwrap_or(0) & 0x3fff;
+        if len < 4 || len > buf.len() {
+            break;
+        }
+        attrs.push((kind, &buf[4..len]));
+        buf = &buf[align(len).min(buf.len())..];
+    }
+    attrs
+}
+
+// One inet_diag_msg with the attributes we asked for.
+struct InetDiagSocket {
+    state: u8,
+    timer: u8,
+    retrans: u8,
+    expires_ms: u32,
+    local_ip: IpAddr,
+    local_port: u16,
+    remote_ip: IpAddr,
+    remote_port: u16,
+    rx_queue: u32,
+    tx_queue: u32,
+    uid: u32,
+    inode: u32,
+    tcp_info: Option<TcpInfo>,
+    congestion: Option<String>,
+    skmem: Option<SkMemInfo>,
+}
+
+fn inet_diag_dump(
+    sock: &mut NetlinkSocket,
+    family: u8,
+    protocol: u8,
+    extensions: u8,
+) -> Result<Vec<InetDiagSocket>> {
+    // inet_diag_req_v2: family, protocol, ext, pad, states, then a zeroed inet_diag_sockid.
+    let mut req = vec![family, protocol, extensions, 0];
+    req.extend_from_slice(&u32::MAX.to_ne_bytes());
+    req.extend_from_slice(&[0u8; 48]);
+
+    let output = sock
+        .dump(SOCK_DIAG_BY_FAMILY, &req)?
+        .into_iter()
+        .filter(|(kind, _)| *kind == SOCK_DIAG_BY_FAMILY)
+        .filter_map(|(_, body)| parse_inet_diag_msg(&body))
+        .collect();
+    Ok(output)
+}
+
+fn parse_inet_diag_msg(body: &[u8]) -> Option<InetDiagSocket> {
+    if body.len() < 72 {
+        return None;
+    }
+
+    let address = |offset: usize| -> IpAddr {
+        if body[0] == libc::AF_INET6 as u8 {
+            let octets: [u8; 16] = body[offset..offset + 16].try_into().unwrap();
+            IpAddr::V6(Ipv6Addr::from(octets))
+        } else {
+            let octets: [u8; 4] = body[offset..offset + 4].try_into().unwrap();
+            IpAddr::V4(Ipv4Addr::from(octets))
+        }
+    };
+
+    let mut socket = InetDiagSocket {
+        state: body[1],
+        timer: body[2],
+        retrans: body[3],
+        expires_ms: read_u32(body, 52).unwrap_or(0),
+        local_port: u16::from_be_bytes([body[4], body[5]]),
+        remote_port: u16::from_be_bytes([body[6], body[7]]),
+        local_ip: address(8),
+        remote_ip: address(24),
+        rx_queue: read_u32(body, 56).unwrap_or(0),
+        tx_queue: read_u32(body, 60).unwrap_or(0),
+        uid: read_u32(body, 64).unwrap_or(0),
+        inode: read_u32(body, 68).unwrap_or(0),
+        tcp_info: None,
+        congestion: None,
+        skmem: None,
+    };
+
+    for (attr, payload) in parse_attrs(&body[72..]) {
+        match attr {
+            INET_DIAG_INFO => socket.tcp_info = Some(parse_tcp_info(payload)),
+            INET_DIAG_CONG => {
+                let name = payload.split(|b| *b == 0).next().unwrap_or_default();
+                socket.congestion = Some(String::from_utf8_lossy(name).into_owned());
+            }
+            INET_DIAG_SKMEMINFO => socket.skmem = Some(parse_skmem(payload)),
+            _ => {}
+        }
+    }
+    Some(socket)
+}
+
+// u32 array indexed by SK_MEMINFO_* (linux/sock_diag.h).
+fn parse_skmem(buf: &[u8]) -> SkMemInfo {
+    let field = |i: usize| read_u32(buf, i * 4).unwrap_or(0);
+    SkMemInfo {
+        rmem_alloc: field(0),
+        rcvbuf: field(1),
+        wmem_alloc: field(2),
+        sndbuf: field(3),
+        fwd_alloc: field(4),
+        wmem_queued: field(5),
+        optmem: field(6),
+        backlog: field(7),
+        drops: field(8),
+    }
+}
+
+// struct tcp_info grows with every kernel release, so fields are read by offset and
+// anything past the end of what this kernel sent is left at zero.
+fn parse_tcp_info(buf: &[u8]) -> TcpInfo {
+    let byte = |offset: usize| buf.get(offset).copied().unwrap_or(0);
+    let u32_at = |offset: usize| read_u32(buf, offset).unwrap_or(0);
+    let u64_at = |offset: usize| read_u64(buf, offset).unwrap_or(0);
+    TcpInfo {
+        ca_state: byte(1),
+        retransmits: byte(2),
+        probes: byte(3),
+        backoff: byte(4),
+        rto_us: u32_at(8),
+        snd_mss: u32_at(16),
+        unacked: u32_at(24),
+        lost: u32_at(32),
+        retrans: u32_at(36),
+        rtt_us: u32_at(68),
+        rttvar_us: u32_at(72),
+        snd_ssthresh: u32_at(76),
+        snd_cwnd: u32_at(80),
+        total_retrans: u32_at(100),
+        pacing_rate: u64_at(104),
+        bytes_acked: u64_at(120),
+        bytes_received: u64_at(128),
+        min_rtt_us: u32_at(148),
+        delivery_rate: u64_at(160),
+        bytes_retrans: u64_at(208),
+    }
+}
+
+fn inet_diag_all(protocol: u8, extensions: u8) -> Result<Vec<InetDiagSocket>> {
+    let mut sock = NetlinkSocket::open(libc::NETLINK_SOCK_DIAG)?;
+    let mut output = inet_diag_dump(&mut sock, libc::AF_INET as u8, protocol, extensions)?;
+    output.extend(inet_diag_dump(
+        &mut sock,
+        libc::AF_INET6 as u8,
+        protocol,
+        extensions,
+    )?);
+    Ok(output)
+}
+
+pub fn netlink_tcp_stats() -> Result<Vec<TcpStats>> {
+    let extensions = 1 << (INET_DIAG_INFO - 1);
+    let output = inet_diag_all(libc::IPPROTO_TCP as u8, extensions)?
+        .into_iter()
+        .map(|s| TcpStats {
+            local_ip: s.local_ip,
+            local_port: s.local_port,
+            remote_ip: s.remote_ip,
+            remote_port: s.remote_port,
+            state: s.state as u64,
+            // For listeners wqueue is the backlog limit, /proc/net/tcp reports 0 there.
+            tx_queue: if s.state == TCP_LISTEN {
+                0
+            } else {
+                s.tx_queue as u64
+            },
+            rx_queue: s.rx_queue as u64,
+            uid: s.uid,
+            inode: s.inode as u64,
+            timer: tcp_timer(&s),
+            tcp_info: s.tcp_info,
+        })
+        .collect();
+    Ok(output)
+}
+
+// idiag_retrans holds icsk_retransmits while the retransmit timer runs and icsk_probes_out for
+// keepalive and zero window probes. Full sockets report both through tcp_info.
+fn tcp_timer(s: &InetDiagSocket) -> TcpTimer {
+    let (retransmits, probes) = match (&s.tcp_info, s.timer) {
+        (Some(info), _) => (info.retransmits as u32, info.probes as u32),
+        (None, 1) => (s.retrans as u32, 0),
+        (None, 2 | 4) => (0, s.retrans as u32),
+        (None, _) => (0, 0),
+    };
+    TcpTimer {
+        kind: s.timer,
+        expires_ms: s.expires_ms as u64,
+        retransmits,
+        probes,
+    }
+}
+
+// tcp_info, congestion control and socket memory of one TCP connection, looked up by its
+// endpoints instead of dumping the whole table.
+pub fn netlink_tcp_details(key: FlowKey) -> Result<TcpDetails> {
+    let (local_ip, local_port, remote_ip, remote_port) = key;
+    let family = if local_ip.is_ipv4() {
+        libc::AF_INET
+    } else {
+        libc::AF_INET6
+    };
+    let address = |ip: IpAddr| {
+        let mut octets = [0u8; 16];
+        match ip {
+            IpAddr::V4(ip) => octets[..4].copy_from_slice(&ip.octets()),
+            IpAddr::V6(ip) => octets.copy_from_slice(&ip.octets()),
+        }
+        octets
+    };
+    let extensions = (1 << (INET_DIAG_INFO - 1))
+        | (1 << (INET_DIAG_CONG - 1))
+        | (1 << (INET_DIAG_SKMEMINFO - 1));
+
+    // inet_diag_req_v2 with the socket's inet_diag_sockid filled in.
+    let mut req = vec![family as u8, libc::IPPROTO_TCP as u8, extensions, 0];
+    req.extend_from_slice(&u32::MAX.to_ne_bytes());
+    req.extend_from_slice(&local_port.to_be_bytes());
+    req.extend_from_slice(&remote_port.to_be_bytes());
+    req.extend_from_slice(&address(local_ip));
+    req.extend_from_slice(&address(remote_ip));
+    req.extend_from_slice(&0u32.to_ne_bytes());
+    req.extend_from_slice(&INET_DIAG_NOCOOKIE.to_ne_bytes());
+    req.extend_from_slice(&INET_DIAG_NOCOOKIE.to_ne_bytes());
+
+    let mut sock = NetlinkSocket::open(libc::NETLINK_SOCK_DIAG)?;
+    let (_, body) = sock.get(SOCK_DIAG_BY_FAMILY, &req)?;
+    let socket = parse_inet_diag_msg(&body).ok_or(anyhow!("Short inet_diag reply"))?;
+    Ok(TcpDetails {
+        info: socket.tcp_info,
+        congestion: socket.congestion,
+        skmem: socket.skmem,
+    })
+}
+
+pub fn netlink_udp_stats() -> Result<Vec<UdpStats>> {
+    let extensions = 1 << (INET_DIAG_SKMEMINFO - 1);
+    let output = inet_diag_all(libc::IPPROTO_UDP as u8, extensions)?
+        .into_iter()
+        .map(|s| UdpStats {
+            local_ip: s.local_ip,
+            local_port: s.local_port,
+            remote_ip: s.remote_ip,
+            remote_port: s.remote_port,
+            state: s.state as u64,
+            tx_queue: s.tx_queue as u64,
+            rx_queue: s.rx_queue as u64,
+            uid: s.uid,
+            inode: s.inode as u64,
+            drops: s.skmem.map_or(0, |m| m.drops) as u64,
+        })
+        .collect();
+    Ok(output)
+}
+
+// rtnetlink message and attribute types (linux/rtnetlink.h, linux/if_link.h)
+const RTM_NEWLINK: u16 = 16;
+const RTM_GETLINK: u16 = 18;
+const IFLA_ADDRESS: u16 = 1;
+const IFLA_IFNAME: u16 = 3;
+const IFLA_MTU: u16 = 4;
+const IFLA_LINK: u16 = 5;
+const IFLA_MASTER: u16 = 10;
+const IFLA_OPERSTATE: u16 = 16;
+const IFLA_LINKINFO: u16 = 18;
+const IFLA_INFO_KIND: u16 = 1;
+const IFLA_STATS64: u16 = 23;
+const IFLA_LINK_NETNSID: u16 = 37;
+const RTM_NEWNSID: u16 = 88;
+const RTM_GETNSID: u16 = 90;
+const NETNSA_NSID: u16 = 1;
+const NETNSA_FD: u16 = 3;
+
+// Dumps every link with its rtnl_link_stats64 counters. RX/TX fields are folded the
+// same way the kernel does for /proc/net/dev so both backends report identical numbers.
+pub fn netlink_network_stats() -> Result<Vec<NetworkStats>> {
+    let mut sock = NetlinkSocket::open(libc::NETLINK_ROUTE)?;
+    // struct ifinfomsg, all zero: any family, any interface.
+    let req = [0u8; 16];
+
+    let mut output = Vec::new();
+    for (kind, body) in sock.dump(RTM_GETLINK, &req)? {
+        if kind != RTM_NEWLINK || body.len() < 16 {
+            continue;
+        }
+
+        let mut name = None;
+        let mut stats = None;
+        let mut link = LinkInfo {
+            ifindex: read_u32(&body, 4).unwrap_or(0),
+            ..LinkInfo::default()
+        };
+
+        for (attr, payload) in parse_attrs(&body[16..]) {
+            match attr {
+                IFLA_IFNAME => {
+                    name = Some(
+                        String::from_utf8_lossy(payload)
+                            .trim_end_matches('\0')
+                            .to_string(),
+                    )
+                }
+                IFLA_STATS64 => stats = Some(payload),
+                IFLA_ADDRESS => link.mac = Some(format_mac(payload)),
+                IFLA_MTU => link.mtu = read_u32(payload, 0).unwrap_or(0),
+                IFLA_LINK => link.link = read_u32(payload, 0),
+                IFLA_LINK_NETNSID => link.link_netnsid = read_u32(payload, 0).map(|id| id as i32),
+                IFLA_MASTER => link.master = read_u32(payload, 0),
+                IFLA_OPERSTATE => link.operstate = payload.first().copied().unwrap_or(0),
+                IFLA_LINKINFO => {
+                    link.kind = parse_attrs(payload)
+                        .into_iter()
+                        .find(|(nested, _)| *nested == IFLA_INFO_KIND)
+                        .map(|(_, kind)| {
+                            String::from_utf8_lossy(kind)
+                                .trim_end_matches('\0')
+                                .to_string()
+                        })
+                }
+                _ => {}
+            }
+        }
+
+        let (Some(name), Some(stats)) = (name, stats) else {
+            continue;
+        };
+        // struct rtnl_link_stats64; rx_nohandler (23) is missing on old kernels.
+        let opt = |field: usize| read_u64(stats, field * 8);
+        let s = |field: usize| opt(field).unwrap_or(0);
+
+        output.push(NetworkStats {
+            name,
+            receive: Receive {
+                bytes: s(2),
+                packets: s(0),
+                errs: s(4),
+                drop: s(6) + s(15),
+                fifo: s(14),
+                frame: s(10) + s(11) + s(12) + s(13),
+                compressed: s(21),
+                multicast: s(8),
+                dropped: opt(6),
+                missed_errors: opt(15),
+                length_errors: opt(10),
+                over_errors: opt(11),
+                crc_errors: opt(12),
+                frame_errors: opt(13),
+                nohandler: opt(23),
+            },
+            transmit: Transmit {
+                bytes: s(3),
+                packets: s(1),
+                errs: s(5),
+                drop: s(7),
+                fifo: s(18),
+                colls: s(9),
+                carrier: s(17) + s(16) + s(20) + s(19),
+                compressed: s(22),
+                aborted_errors: opt(16),
+                carrier_errors: opt(17),
+                heartbeat_errors: opt(19),
+                window_errors: opt(20),
+            },
+            link: Some(link),
+        });
+    }
+    Ok(output)
+}
+
+// The id the current namespace has for the namespace open as `ns`, which is what
+// IFLA_LINK_NETNSID refers to. `None` when no id has been assigned.
+pub fn netlink_netns_id(ns: &File) -> Result<Option<i32>> {
+    // struct rtgenmsg padded to 4 bytes, then NETNSA_FD.
+    let mut req = vec![libc::AF_UNSPEC as u8, 0, 0, 0];
+    req.extend_from_slice(&8u16.to_ne_bytes());
+    req.extend_from_slice(&NETNSA_FD.to_ne_bytes());
+    req.extend_from_slice(&(ns.as_raw_fd() as u32).to_ne_bytes());
+
+    let mut sock = NetlinkSocket::open(libc::NETLINK_ROUTE)?;
+    let (kind, body) = sock.get(RTM_GETNSID, &req)?;
+    if kind != RTM_NEWNSID || body.len() < 4 {
+        return Err(anyhow!("Unexpected RTM_GETNSID reply"));
+    }
+    Ok(parse_attrs(&body[4..])
+        .into_iter()
+        .find(|(attr, _)| *attr == NETNSA_NSID)
+        .and_then(|(_, payload)| read_u32(payload, 0))
+        .map(|id| id as i32)
+        .filter(|id| *id >= 0))
+}
+
+const RTM_NEWNEIGH: u16 = 28;
+const RTM_GETNEIGH: u16 = 30;
+const NDA_DST: u16 = 1;
+const NDA_LLADDR: u16 = 2;
+const NUD_INCOMPLETE: u16 = 0x01;
+const NUD_FAILED: u16 = 0x20;
+const NUD_NOARP: u16 = 0x40;
+const NTF_PROXY: u8 = 0x08;
+const NTF_ROUTER: u8 = 0x80;
+
+// IPv6 neighbour cache; IPv4 comes from /proc/net/arp. NOARP entries (multicast and the like)
+// are skipped, as `ip neigh` does.
+pub fn netlink_ipv6_neighbors() -> Result<Vec<Neighbor>> {
+    let mut sock = NetlinkSocket::open(libc::NETLINK_ROUTE)?;
+    // struct ndmsg: family, pad, pad, ifindex, state, flags, type.
+    let mut req = [0u8; 12];
+    req[0] = libc::AF_INET6 as u8;
+
+    let mut output = Vec::new();
+    for (kind, body) in sock.dump(RTM_GETNEIGH, &req)? {
+        if kind != RTM_NEWNEIGH || body.len() < 12 {
+            continue;
+        }
+        let ifindex = read_u32(&body, 4).unwrap_or(0);
+        let state = read_u16(&body, 8).unwrap_or(0);
+        let flags = body[10];
+        if state & NUD_NOARP != 0 {
+            continue;
+        }
+
+        let mut ip = None;
+        let mut mac = None;
+        for (attr, payload) in parse_attrs(&body[12..]) {
+            match attr {
+                NDA_DST => {
+                    ip = <[u8; 16]>::try_from(payload)
+                        .ok()
+                        .map(|o| IpAddr::V6(Ipv6Addr::from(o)))
+                }
+                NDA_LLADDR => mac = Some(format_mac(payload)),
+                _ => {}
+            }
+        }
+        let Some(ip) = ip else {
+            continue;
+        };
+
+        // Same letters as the ARP entries: R router, P proxy
+        let letters: String = [(NTF_ROUTER, 'R'), (NTF_PROXY, 'P')]
+            .iter()
+            .filter(|(bit, _)| flags & bit != 0)
+            .map(|(_, letter)| *letter)
+            .collect();
+        output.push(Neighbor {
+            ip,
+            mac,
+            device: interface_name(ifindex).unwrap_or(format!("if{}", ifindex)),
+            flags: letters,
+            state: neigh_state_name(state),
+            incomplete: state & (NUD_INCOMPLETE | NUD_FAILED) != 0,
+        });
+    }
+    Ok(output)
+}
+
+// ctnetlink (linux/netfilter/nfnetlink_conntrack.h). Every value is in network byte order.
+const IPCTNL_MSG_CT_GET: u16 = (1 << 8) | 1;
+const IPCTNL_MSG_CT_NEW: u16 = 1 << 8;
+const CTA_TUPLE_ORIG: u16 = 1;
+const CTA_TUPLE_REPLY: u16 = 2;
+const CTA_STATUS: u16 = 3;
+const CTA_PROTOINFO: u16 = 4;
+const CTA_TIMEOUT: u16 = 7;
+const CTA_MARK: u16 = 8;
+const CTA_COUNTERS_ORIG: u16 = 9;
+const CTA_COUNTERS_REPLY: u16 = 10;
+const CTA_TUPLE_IP: u16 = 1;
+const CTA_TUPLE_PROTO: u16 = 2;
+const CTA_PROTO_NUM: u16 = 1;
+const CTA_PROTO_SRC_PORT: u16 = 2;
+const CTA_PROTO_DST_PORT: u16 = 3;
+const CTA_PROTOINFO_TCP: u16 = 1;
+const CTA_PROTOINFO_TCP_STATE: u16 = 1;
+const CTA_COUNTERS_PACKETS: u16 = 1;
+const CTA_COUNTERS_BYTES: u16 = 2;
+const IPS_SEEN_REPLY: u32 = 1 << 1;
+const IPS_ASSURED: u32 = 1 << 2;
+
+// The whole conntrack table of the current namespace, both address families. Needs
+// CAP_NET_ADMIN; /proc/net/nf_conntrack is the fallback.
+pub fn netlink_conntrack() -> Result<Vec<ConntrackEntry>> {
+    let mut sock = NetlinkSocket::open(libc::NETLINK_NETFILTER)?;
+    // struct nfgenmsg: family (AF_UNSPEC for all), version, res_id.
+    let req = [0u8; 4];
+
+    let mut output = Vec::new();
+    for (kind, body) in sock.dump(IPCTNL_MSG_CT_GET, &req)? {
+        if kind != IPCTNL_MSG_CT_NEW || body.len() < 4 {
+            continue;
+        }
+
+        let mut original = None;
+        let mut reply = None;
+        let mut protocol = 0;
+        let mut state = None;
+        let mut status = 0;
+        let mut timeout = 0;
+        let mut mark = 0;
+        let mut orig_counters = (None, None);
+        let mut reply_counters = (None, None);
+        for (attr, payload) in parse_attrs(&body[4..]) {
+            match attr {
+                CTA_TUPLE_ORIG => {
+                    if let Some((tuple, proto)) = parse_ct_tuple(payload) {
+                        original = Some(tuple);
+                        protocol = proto;
+                    }
+                }
+                CTA_TUPLE_REPLY => reply = parse_ct_tuple(payload).map(|(tuple, _)| tuple),
+                CTA_STATUS => status = read_be32(payload).unwrap_or(0),
+                CTA_TIMEOUT => timeout = read_be32(payload).unwrap_or(0),
+                CTA_MARK => mark = read_be32(payload).unwrap_or(0),
+                CTA_PROTOINFO => {
+                    for (info, payload) in parse_attrs(payload) {
+                        if info != CTA_PROTOINFO_TCP {
+                            continue;
+                        }
+                        for (attr, value) in parse_attrs(payload) {
+                            if attr == CTA_PROTOINFO_TCP_STATE && !value.is_empty() {
+                                state = Some(conntrack_tcp_state_name(value[0]).to_string());
+                            }
+                        }
+                    }
+                }
+                CTA_COUNTERS_ORIG => orig_counters = parse_ct_counters(payload),
+                CTA_COUNTERS_REPLY => reply_counters = parse_ct_counters(payload),
+                _ => {}
+            }
+        }
+        let (Some(original), Some(reply)) = (original, reply) else {
+            continue;
+        };
+
+        output.push(ConntrackEntry {
+            protocol: ip_protocol_name(protocol).to_string(),
+            state,
+            timeout: timeout as u64,
+            original,
+            reply,
+            orig_packets: orig_counters.0,
+            orig_bytes: orig_counters.1,
+            reply_packets: reply_counters.0,
+            reply_bytes: reply_counters.1,
+            assured: status & IPS_ASSURED != 0,
+            unreplied: status & IPS_SEEN_REPLY == 0,
+            mark,
+        });
+    }
+    Ok(output)
+}
+
+// CTA_TUPLE_IP holds the addresses (v4 src/dst are types 1/2, v6 3/4), CTA_TUPLE_PROTO the
+// protocol number and, for TCP/UDP/SCTP, the ports.
+fn parse_ct_tuple(buf: &[u8]) -> Option<(ConntrackTuple, u8)> {
+    let mut src = None;
+    let mut dst = None;
+    let mut sport = None;
+    let mut dport = None;
+    let mut protocol = 0;
+    for (attr, payload) in parse_attrs(buf) {
+        match attr {
+            CTA_TUPLE_IP => {
+                for (kind, addr) in parse_attrs(payload) {
+                    let ip = match addr.len() {
+                        4 => IpAddr::V4(Ipv4Addr::from(<[u8; 4]>::try_from(addr).ok()?)),
+                        16 => IpAddr::V6(Ipv6Addr::from(<[u8; 16]>::try_from(addr).ok()?)),
+                        _ => continue,
+                    };
+                    match kind {
+                        1 | 3 => src = Some(ip),
+                        2 | 4 => dst = Some(ip),
+                        _ => {}
+                    }
+                }
+            }
+            CTA_TUPLE_PROTO => {
+                for (kind, value) in parse_attrs(payload) {
+                    let port = value.get(..2).map(|b| u16::from_be_bytes([b[0], b[1]]));
+                    match kind {
+                        CTA_PROTO_NUM => protocol = value.first().copied().unwrap_or(0),
+                        CTA_PROTO_SRC_PORT => sport = port,
+                        CTA_PROTO_DST_PORT => dport = port,
+                        _ => {}
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+    Some((
+        ConntrackTuple {
+            src: src?,
+            dst: dst?,
+            sport,
+            dport,
+        },
+        protocol,
+    ))
+}
+
+fn parse_ct_counters(buf: &[u8]) -> (Option<u64>, Option<u64>) {
+    let mut counters = (None, None);
+    for (kind, value) in parse_attrs(buf) {
+        let value = value
+            .get(..8)
+            .and_then(|b| b.try_into().ok())
+            .map(u64::from_be_bytes);
+        match kind {
+            CTA_COUNTERS_PACKETS => counters.0 = value,
+            CTA_COUNTERS_BYTES => counters.1 = value,
+            _ => {}
+        }
+    }
+    counters
+}
+
+fn read_be32(buf: &[u8]) -> Option<u32> {
+    Some(u32::from_be_bytes(buf.get(..4)?.try_into().ok()?))
+}
+
+// Protocol names as /proc/net/nf_conntrack prints them.
+fn ip_protocol_name(protocol: u8) -> &'static str {
+    match protocol {
+        1 => "icmp",
+        6 => "tcp",
+        17 => "udp",
+        33 => "dccp",
+        47 => "gre",
+        58 => "icmpv6",
+        132 => "sctp",
+        136 => "udplite",
+        _ => "unknown",
+    }
+}
+
+fn interface_name(ifindex: u32) -> Option<String> {
+    let mut buf = [0u8; libc::IF_NAMESIZE];
+    let name = unsafe { libc::if_indextoname(ifindex, buf.as_mut_ptr() as *mut libc::c_char) };
+    if name.is_null() {
+        return None;
+    }
+    let end = buf.iter().position(|b| *b == 0).unwrap_or(buf.len());
+    Some(String::from_utf8_lossy(&buf[..end]).to_string())
+}
+
+fn format_mac(bytes: &[u8]) -> String {
+    bytes
+        .iter()
+        .map(|b| format!("{:02x}", b))
+        .collect::<Vec<_>>()
+        .join(":")
+}
+
+const SIOCETHTOOL: libc::c_ulong = 0x8946;
+const ETHTOOL_GSTRINGS: u32 = 0x1b;
+const ETHTOOL_GSTATS: u32 = 0x1d;
+const ETHTOOL_GSSET_INFO: u32 = 0x37;
+const ETH_SS_STATS: u32 = 1;
+const ETH_GSTRING_LEN: usize = 32;
+
+// `ethtool -S`: the driver's own counters as (name, value). Names and meaning are driver
+// specific; many include per-queue packets and bytes.
+pub fn ethtool_stats(interface: &str) -> Result<Vec<(String, u64)>> {
+    let name = interface.as_bytes();
+    if name.len() >= libc::IFNAMSIZ {
+        return Err(anyhow!("Interface name too long"));
+    }
+    let fd = unsafe { libc::socket(libc::AF_INET, libc::SOCK_DGRAM | libc::SOCK_CLOEXEC, 0) };
+    if fd < 0 {
+        return Err(io::Error::last_os_error().into());
+    }
+    let fd = unsafe { OwnedFd::from_raw_fd(fd) };
+
+    // struct ifreq: ifr_name[IFNAMSIZ] then ifr_data, a pointer to the ethtool command. The
+    // kernel copies the whole struct in and out, union padding included.
+    let call = |cmd: &mut [u8]| -> Result<()> {
+        let mut req = [0u8; std::mem::size_of::<libc::ifreq>()];
+        req[..name.len()].copy_from_slice(name);
+        let ptr = (cmd.as_mut_ptr() as usize).to_ne_bytes();
+        req[libc::IFNAMSIZ..libc::IFNAMSIZ + ptr.len()].copy_from_slice(&ptr);
+        if unsafe { libc::ioctl(fd.as_raw_fd(), SIOCETHTOOL as _, req.as_mut_ptr()) } < 0 {
+            return Err(io::Error::last_os_error().into());
+        }
+        Ok(())
+    };
+
+    // struct ethtool_sset_info: cmd, reserved, sset_mask, then one u32 per requested set.
+    let mut info = [0u8; 20];
+    info[..4].copy_from_slice(&ETHTOOL_GSSET_INFO.to_ne_bytes());
+    info[8..16].copy_from_slice(&(1u64 << ETH_SS_STATS).to_ne_bytes());
+    call(&mut info)?;
+    let count = read_u32(&info, 16).unwrap_or(0) as usize;
+    if count == 0 {
+        return Ok(Vec::new());
+    }
+
+    // struct ethtool_gstrings: cmd, string_set, len, then len fixed-size names.
+    let mut strings = vec![0u8; 12 + count * ETH_GSTRING_LEN];
+    strings[..4].copy_from_slice(&ETHTOOL_GSTRINGS.to_ne_bytes());
+    strings[4..8].copy_from_slice(&ETH_SS_STATS.to_ne_bytes());
+    strings[8..12].copy_from_slice(&(count as u32).to_ne_bytes());
+    call(&mut strings)?;
+
+    // struct ethtool_stats: cmd, n_stats, then n_stats u64 values.
+    let mut stats = vec![0u8; 8 + count * 8];
+    stats[..4].copy_from_slice(&ETHTOOL_GSTATS.to_ne_bytes());
+    stats[4..8].copy_from_slice(&(count as u32).to_ne_bytes());
+    call(&mut stats)?;
+
+    Ok((0..count)
+        .filter_map(|i| {
+            let raw = &strings[12 + i * ETH_GSTRING_LEN..12 + (i + 1) * ETH_GSTRING_LEN];
+            let end = raw.iter().position(|b| *b == 0).unwrap_or(raw.len());
+            let name = String::from_utf8_lossy(&raw[..end]).to_string();
+            Some((name, read_u64(&stats, 8 + i * 8)?))
+        })
+        .collect())
+}
+
+// Wireless extensions SIOCGIWRATE; still answered by cfg80211 drivers and far simpler than
+// resolving the nl80211 generic netlink family just for the bitrate.
+const SIOCGIWRATE: libc::c_ulong = 0x8B21;
+
+pub fn wireless_bitrate(interface: &str) -> Option<u32> {
+    let name = interface.as_bytes();
+    if name.len() >= libc::IFNAMSIZ {
+        return None;
+    }
+
+    // struct iwreq: ifr_name[IFNAMSIZ] followed by a 16-byte union; iw_param.value (bit/s) leads.
+    let mut req = [0u8; libc::IFNAMSIZ + 16];
+    req[..name.len()].copy_from_slice(name);
+
+    let fd = unsafe { libc::socket(libc::AF_INET, libc::SOCK_DGRAM | libc::SOCK_CLOEXEC, 0) };
+    if fd < 0 {
+        return None;
+    }
+    let fd = unsafe { OwnedFd::from_raw_fd(fd) };
+
+    if unsafe { libc::ioctl(fd.as_raw_fd(), SIOCGIWRATE as _, req.as_mut_ptr()) } < 0 {
+        return None;
+    }
+    read_u32(&req, libc::IFNAMSIZ).filter(|bps| *bps > 0)
+}
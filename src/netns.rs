@@ -0,0 +1,236 @@
+use crate::models::{NetNs, VethLink, VethPeer};
+use crate::netlink::{netlink_netns_id, netlink_network_stats};
+use crate::parser::{lookup_container_name, parse_cgroup_container};
+use anyhow::{anyhow, Context, Result};
+use std::collections::HashMap;
+use std::fs::{self, File};
+use std::os::fd::AsRawFd;
+use std::os::unix::fs::MetadataExt;
+use std::path::{Path, PathBuf};
+
+// Where `ip netns add` bind-mounts named namespaces.
+const NAMED_NETNS_DIR: &str = "/var/run/netns";
+
+// A namespace is identified by its nsfs inode; the same one is reachable through a named
+// bind mount and through /proc/<pid>/ns/net of every process inside it.
+pub fn netns_inode(path: &Path) -> Option<u64> {
+    fs::metadata(path).ok().map(|m| m.ino())
+}
+
+// Named namespaces first, then one entry per namespace some process lives in. The namespace
+// of PID 1 (or of kthreadd, when PID 1 is off limits) is labelled `host`.
+pub fn list_namespaces() -> Vec<NetNs> {
+    let mut namespaces: Vec<NetNs> = Vec::new();
+    let mut by_inode: HashMap<u64, usize> = HashMap::new();
+    let host = netns_inode(Path::new("/proc/1/ns/net")).or_else(|| {
+        let comm = fs::read_to_string("/proc/2/comm").unwrap_or_default();
+        (comm.trim() == "kthreadd")
+            .then(|| netns_inode(Path::new("/proc/2/ns/net")))
+            .flatten()
+    });
+
+    if let std::result::Result::Ok(entries) = fs::read_dir(NAMED_NETNS_DIR) {
+        let mut named: Vec<(String, PathBuf)> = entries
+            .flatten()
+            .map(|e| (e.file_name().to_string_lossy().to_string(), e.path()))
+            .collect();
+        named.sort();
+        for (name, path) in named {
+            if let Some(inode) = netns_inode(&path) {
+                by_inode.entry(inode).or_insert(namespaces.len());
+                namespaces.push(NetNs {
+                    name,
+                    path,
+                    inode,
+                    processes: 0,
+                });
+            }
+        }
+    }
+
+    for (pid, inode) in process_namespaces() {
+        let path = PathBuf::from(format!("/proc/{}/ns/net", pid));
+        if let Some(&i) = by_inode.get(&inode) {
+            namespaces[i].processes += 1;
+            continue;
+        }
+
+        let name = if Some(inode) == host {
+            "host".to_string()
+        } else {
+            let comm = fs::read_to_string(format!("/proc/{}/comm", pid)).unwrap_or_default();
+            format!("pid {} ({})", pid, comm.trim())
+        };
+        by_inode.insert(inode, namespaces.len());
+        namespaces.push(NetNs {
+            name,
+            path,
+            inode,
+            processes: 1,
+        });
+    }
+    namespaces
+}
+
+// (pid, netns inode) of every process we can see, in PID order.
+fn process_namespaces() -> Vec<(u32, u64)> {
+    let mut pids: Vec<u32> = fs::read_dir("/proc")
+        .map(|entries| {
+            entries
+                .flatten()
+                .filter_map(|e| e.file_name().to_str()?.parse().ok())
+                .collect()
+        })
+        .unwrap_or_default();
+    pids.sort();
+
+    pids.into_iter()
+        .filter_map(|pid| {
+            Some((
+                pid,
+                netns_inode(Path::new(&format!("/proc/{}/ns/net", pid)))?,
+            ))
+        })
+        .collect()
+}
+
+// Finds the far end of each veth by looking for the interface whose ifindex is our iflink and
+// whose iflink is our ifindex in every other namespace. Ifindexes repeat across namespaces, so
+// a veth that knows its peer's netnsid is only matched in that namespace. Must be called from
+// the current namespace, which the netnsids are relative to; namespaces we can't enter are
+// skipped.
+pub fn resolve_veth_peers(links: &[VethLink], current: u64) -> HashMap<String, VethPeer> {
+    let namespaces: Vec<NetNs> = list_namespaces()
+        .into_iter()
+        .filter(|ns| ns.inode != current)
+        .collect();
+    let nsids: Vec<Option<i32>> = namespaces
+        .iter()
+        .map(|ns| {
+            open_netns(&ns.path)
+                .and_then(|file| netlink_netns_id(&file))
+                .ok()
+                .flatten()
+        })
+        .collect();
+
+    // Entering a namespace is per thread, so the dumps run on a throwaway one.
+    let found: Vec<(String, String, usize)> = std::thread::scope(|s| {
+        s.spawn(|| {
+            let mut found = Vec::new();
+            for (i, ns) in namespaces.iter().enumerate() {
+                let entered = open_netns(&ns.path).and_then(|file| enter_netns(&file));
+                let (std::result::Result::Ok(()), std::result::Result::Ok(stats)) =
+                    (entered, netlink_network_stats())
+                else {
+                    continue;
+                };
+                for remote in stats {
+                    let Some(link) = &remote.link else {
+                        continue;
+                    };
+                    if let Some(veth) = links.iter().find(|veth| {
+                        veth.iflink == link.ifindex
+                            && link.link == Some(veth.ifindex)
+                            && veth.netnsid.is_none_or(|id| nsids[i] == Some(id))
+                    }) {
+                        found.push((veth.name.clone(), remote.name.clone(), i));
+                    }
+                }
+            }
+            found
+        })
+        .join()
+        .unwrap_or_default()
+    });
+    if found.is_empty() {
+        return HashMap::new();
+    }
+
+    let mut pids: HashMap<u64, Vec<u32>> = HashMap::new();
+    for (pid, inode) in process_namespaces() {
+        pids.entry(inode).or_default().push(pid);
+    }
+
+    found
+        .into_iter()
+        .map(|(name, peer, i)| {
+            let ns = &namespaces[i];
+            let container = pids.get(&ns.inode).and_then(|pids| {
+                pids.iter().find_map(|pid| {
+                    let cgroup = fs::read_to_string(format!("/proc/{}/cgroup", pid)).ok()?;
+                    parse_cgroup_container(&cgroup)
+                })
+            });
+            let container = container.map(|mut c| {
+                if c.name.is_none() {
+                    c.name = lookup_container_name(c.runtime, &c.id);
+                }
+                c
+            });
+            (
+                name,
+                VethPeer {
+                    peer,
+                    netns: ns.name.clone(),
+                    container,
+                },
+            )
+        })
+        .collect()
+}
+
+// `--netns` accepts a path, a PID or a name under /var/run/netns.
+pub fn resolve_netns(arg: &str) -> Result<NetNs> {
+    let path = if Path::new(arg).exists() {
+        PathBuf::from(arg)
+    } else if let std::result::Result::Ok(pid) = arg.parse::<u32>() {
+        PathBuf::from(format!("/proc/{}/ns/net", pid))
+    } else {
+        Path::new(NAMED_NETNS_DIR).join(arg)
+    };
+    let inode = netns_inode(&path).ok_or(anyhow!("no network namespace at {}", path.display()))?;
+
+    let name = list_namespaces()
+        .into_iter()
+        .find(|ns| ns.inode == inode)
+        .map(|ns| ns.name)
+        .unwrap_or(arg.to_string());
+    Ok(NetNs {
+        name,
+        path,
+        inode,
+        processes: 0,
+    })
+}
+
+// setns(2) only moves the calling thread. Collection runs on the main thread, and /proc/net
+// follows it, as does every netlink socket opened afterwards.
+pub fn enter_netns(file: &File) -> Result<()> {
+    if unsafe { libc::setns(file.as_raw_fd(), libc::CLONE_NEWNET) } < 0 {
+        return Err(std::io::Error::last_os_error()).context("setns failed");
+    }
+    Ok(())
+}
+
+pub fn open_netns(path: &Path) -> Result<File> {
+    File::open(path).with_context(|| format!("cannot open {}", path.display()))
+}
+
+// Kept open for the lifetime of the app so switching back never depends on a path that may have
+// moved along with us (/proc/self/ns/net follows setns).
+pub fn open_home_netns() -> Option<(File, NetNs)> {
+    let path = PathBuf::from("/proc/thread-self/ns/net");
+    let file = open_netns(&path).ok()?;
+    let inode = file.metadata().ok()?.ino();
+    let ns = list_namespaces()
+        .into_iter()
+        .find(|ns| ns.inode == inode)
+        .unwrap_or(NetNs {
+            name: "initial".to_string(),
+            path,
+            inode,
+            processes: 0,
+        });
+    Some((file, ns))
+}
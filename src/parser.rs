@@ -1,5 +1,6 @@
 use crate::app::App;
 use crate::models::*;
+use crate::netlink::{ethtool_stats, wireless_bitrate};
 use crate::theme::{Theme, THEMES};
 use anyhow::{anyhow, Error, Ok, Result};
 use once_cell::sync::Lazy;
@@ -7,8 +8,10 @@ use ratatui::text::Line;
 use std::collections::{HashMap, HashSet};
 use std::env::home_dir;
 use std::fs::{self, File, OpenOptions};
-use std::io::{BufRead, BufReader, Read};
+use std::io::{BufRead, BufReader, ErrorKind, Read};
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr};
 use std::path::{Path, PathBuf};
+use std::time::Duration;
 
 pub fn parse_proc_net_dev() -> Result<Vec<NetworkStats>> {
     let mut output = Vec::new();
@@ -29,7 +32,6 @@ pub fn parse_proc_net_dev() -> Result<Vec<NetworkStats>> {
 
             let interface = s[0].trim().to_string();
             let values: Vec<u64> = s[1]
-                .trim()
                 .split_whitespace()
                 .filter_map(|x| x.parse().ok())
                 .collect();
@@ -47,6 +49,7 @@ pub fn parse_proc_net_dev() -> Result<Vec<NetworkStats>> {
                 frame: values[5],
                 compressed: values[6],
                 multicast: values[7],
+                ..Default::default()
             };
             let transmit = Transmit {
                 bytes: values[8],
@@ -57,25 +60,65 @@ pub fn parse_proc_net_dev() -> Result<Vec<NetworkStats>> {
                 colls: values[13],
                 carrier: values[14],
                 compressed: values[15],
+                ..Default::default()
             };
             output.push(NetworkStats {
                 name: interface,
                 receive,
                 transmit,
+                link: None,
             });
         }
     }
     Ok(output)
 }
 
-pub fn get_network_receive_data<'a>(app: &mut App, stats: &Vec<NetworkStats>) -> Vec<Line<'a>> {
+// /proc/net/dev only has the summed counters; sysfs has every counter the kernel keeps, one
+// file each. Only meaningful in the namespace sysfs was mounted in.
+pub fn read_sysfs_statistics(stats: &mut [NetworkStats]) {
+    for interface in stats {
+        let dir = Path::new("/sys/class/net")
+            .join(&interface.name)
+            .join("statistics");
+        let std::result::Result::Ok(entries) = fs::read_dir(dir) else {
+            continue;
+        };
+        let counters: HashMap<String, u64> = entries
+            .flatten()
+            .filter_map(|e| {
+                let value = fs::read_to_string(e.path()).ok()?.trim().parse().ok()?;
+                Some((e.file_name().to_string_lossy().to_string(), value))
+            })
+            .collect();
+        apply_sysfs_statistics(interface, &counters);
+    }
+}
+
+fn apply_sysfs_statistics(interface: &mut NetworkStats, counters: &HashMap<String, u64>) {
+    let get = |name: &str| counters.get(name).copied();
+    let rx = &mut interface.receive;
+    rx.dropped = get("rx_dropped");
+    rx.missed_errors = get("rx_missed_errors");
+    rx.length_errors = get("rx_length_errors");
+    rx.over_errors = get("rx_over_errors");
+    rx.crc_errors = get("rx_crc_errors");
+    rx.frame_errors = get("rx_frame_errors");
+    rx.nohandler = get("rx_nohandler");
+    let tx = &mut interface.transmit;
+    tx.aborted_errors = get("tx_aborted_errors");
+    tx.carrier_errors = get("tx_carrier_errors");
+    tx.heartbeat_errors = get("tx_heartbeat_errors");
+    tx.window_errors = get("tx_window_errors");
+}
+
+pub fn get_network_receive_data<'a>(app: &mut App, stats: &[NetworkStats]) -> Vec<Line<'a>> {
     let lines: Vec<Line> = stats
         .iter()
         .map(|interface| {
             let speed = app
                 .rx_data
                 .get(&interface.name)
-                .and_then(|data| speed_kachow(data.clone()))
+                .and_then(|data| speed_kachow(data))
                 .unwrap_or("0 B/s".to_string());
 
             let sum = interface.receive.bytes + interface.transmit.bytes;
@@ -109,27 +152,41 @@ pub fn human_speed(bytes_per_sec: f64) -> (f64, &'static str) {
         (bytes_per_sec, "B/s")
     }
 }
-pub fn speed_kachow(stats: Vec<(f64, f64)>) -> Option<String> {
+// Shown instead of a rate for a tick where the counters went backwards.
+pub const COUNTER_RESET: &str = "reset";
+
+// Bytes per second over the last tick; `None` before the second sample or across a reset.
+pub fn last_rate(stats: &[Sample]) -> Option<f64> {
+    let [.., (t1, _), (t2, Some(bytes))] = stats else {
+        return None;
+    };
+    Some(bytes / (t2 - t1))
+}
+
+pub fn speed_kachow(stats: &[Sample]) -> Option<String> {
     if stats.len() < 2 {
         return Some("0 B/s".to_string());
     }
 
     let (t1, _) = stats[stats.len() - 2];
     let (t2, d2) = stats[stats.len() - 1];
+    let Some(d2) = d2 else {
+        return Some(COUNTER_RESET.to_string());
+    };
     let dt = t2 - t1;
-    let speed = d2 as f64 / dt;
+    let speed = d2 / dt;
     let (s, string) = human_speed(speed);
     Some(format!("{:.2} {}", s, string))
 }
 
-pub fn get_network_transmit_data<'a>(app: &mut App, stats: &Vec<NetworkStats>) -> Vec<Line<'a>> {
+pub fn get_network_transmit_data<'a>(app: &mut App, stats: &[NetworkStats]) -> Vec<Line<'a>> {
     let lines: Vec<Line> = stats
         .iter()
         .map(|interface| {
             let speed = app
                 .tx_data
                 .get(&interface.name)
-                .and_then(|data| speed_kachow(data.clone()))
+                .and_then(|data| speed_kachow(data))
                 .unwrap_or("0 B/s".to_string());
 
             let sum = interface.receive.bytes + interface.transmit.bytes;
@@ -144,21 +201,32 @@ pub fn get_network_transmit_data<'a>(app: &mut App, stats: &Vec<NetworkStats>) -
         .collect();
     lines
 }
-pub fn parse_ip_address(s: &str) -> Result<([u8; 4], u16)> {
+// Addresses in /proc/net/{tcp,tcp6} are printed as 32-bit words in host byte order,
+// 8 hex chars for IPv4 and 32 for IPv6.
+pub fn parse_ip_address(s: &str) -> Result<(IpAddr, u16)> {
     let mut s = s.split(":");
     let ip_hex_value = s.next().ok_or(anyhow!("Failed to parse IP"))?;
-    let ip_bytes = (0..4)
-        .map(|x| u8::from_str_radix(&ip_hex_value[2 * x..2 * x + 2], 16))
-        .collect::<Result<Vec<_>, _>>()
-        .unwrap();
-    let ip = [ip_bytes[3], ip_bytes[2], ip_bytes[1], ip_bytes[0]];
+    let words = (0..ip_hex_value.len() / 8)
+        .map(|x| u32::from_str_radix(&ip_hex_value[8 * x..8 * x + 8], 16))
+        .collect::<Result<Vec<_>, _>>()?;
+    let ip = match words.as_slice() {
+        [w] => IpAddr::V4(Ipv4Addr::from(w.to_ne_bytes())),
+        [a, b, c, d] => {
+            let mut octets = [0u8; 16];
+            for (i, w) in [a, b, c, d].iter().enumerate() {
+                octets[4 * i..4 * i + 4].copy_from_slice(&w.to_ne_bytes());
+            }
+            IpAddr::V6(Ipv6Addr::from(octets))
+        }
+        _ => return Err(anyhow!("Failed to parse IP")),
+    };
     let port_hex_value = s.next().ok_or(anyhow!("Failed to parse PORT"))?;
     let port = u16::from_str_radix(port_hex_value, 16)?;
     Ok((ip, port))
 }
 
 pub fn parse_hex_values(s: &str) -> Result<u64> {
-    let hex_value = u64::from_str_radix(&s, 16)?;
+    let hex_value = u64::from_str_radix(s, 16)?;
     Ok(hex_value)
 }
 
@@ -176,47 +244,172 @@ pub fn parse_hex_value_pairs(s: &str) -> Result<(u64, u64)> {
 }
 
 pub fn parse_proc_net_tcp() -> Result<Vec<TcpStats>> {
-    let net_tcp_file = PathBuf::from("/proc/net/tcp");
+    let mut output = parse_tcp_file(Path::new("/proc/net/tcp"))?;
+    output.extend(parse_tcp_file(Path::new("/proc/net/tcp6"))?);
+    Ok(output)
+}
+
+// The v6 table is missing on hosts booted with ipv6.disable=1, which just means no sockets.
+fn parse_tcp_file(net_tcp_file: &Path) -> Result<Vec<TcpStats>> {
+    match fs::read_to_string(net_tcp_file) {
+        Err(e) if e.kind() == ErrorKind::NotFound => Ok(Vec::new()),
+        content => parse_tcp_table(&content?),
+    }
+}
+
+pub fn parse_tcp_table(content: &str) -> Result<Vec<TcpStats>> {
     let mut output = Vec::new();
-    if let Err(e) = fs::metadata(&net_tcp_file) {
-        eprintln!("{e}");
-    } else {
-        let file = File::open(&net_tcp_file)?;
-        let reader = BufReader::new(file);
-        for line in reader.lines().skip(1) {
-            let line = line?;
-            let first_split: Vec<&str> = line.trim().split_whitespace().collect();
+    for line in content.lines().skip(1) {
+        let first_split: Vec<&str> = line.split_whitespace().collect();
 
-            if first_split.len() < 12 {
-                continue;
-            }
+        if first_split.len() < 12 {
+            continue;
+        }
 
-            let (local_ip, local_port) = parse_ip_address(first_split[1])?;
-            let (remote_ip, remote_port) = parse_ip_address(first_split[2])?;
+        let (local_ip, local_port) = parse_ip_address(first_split[1])?;
+        let (remote_ip, remote_port) = parse_ip_address(first_split[2])?;
 
-            let state = parse_hex_values(first_split[3])?;
-            let (tx_queue, rx_queue) = parse_hex_value_pairs(first_split[4])?;
+        let state = parse_hex_values(first_split[3])?;
+        let (tx_queue, rx_queue) = parse_hex_value_pairs(first_split[4])?;
+        // tm->when is in USER_HZ clock ticks, 100 per second.
+        let (timer, when) = parse_hex_value_pairs(first_split[5])?;
+        let retransmits = parse_hex_values(first_split[6])?;
 
-            let uid = first_split[7].parse::<u32>()?;
-            let inode = first_split[9].parse::<u64>()?;
+        let uid = first_split[7].parse::<u32>()?;
+        let probes = first_split[8].parse::<u32>()?;
+        let inode = first_split[9].parse::<u64>()?;
 
-            let values = TcpStats {
-                local_ip: local_ip,
-                local_port: local_port,
-                remote_ip: remote_ip,
-                remote_port: remote_port,
-                state: state,
-                tx_queue: tx_queue,
-                rx_queue: rx_queue,
-                uid: uid,
-                inode: inode,
-            };
-            output.push(values);
+        let values = TcpStats {
+            local_ip,
+            local_port,
+            remote_ip,
+            remote_port,
+            state,
+            tx_queue,
+            rx_queue,
+            uid,
+            inode,
+            timer: TcpTimer {
+                kind: timer as u8,
+                expires_ms: when * 10,
+                retransmits: retransmits as u32,
+                probes,
+            },
+            tcp_info: None,
+        };
+        output.push(values);
+    }
+    Ok(output)
+}
+
+pub fn parse_proc_net_udp() -> Result<Vec<UdpStats>> {
+    let mut output = parse_udp_file(Path::new("/proc/net/udp"))?;
+    output.extend(parse_udp_file(Path::new("/proc/net/udp6"))?);
+    Ok(output)
+}
+
+// Same layout as /proc/net/tcp, with an extra trailing `drops` column. Like tcp6, udp6 is missing
+// when IPv6 is disabled.
+fn parse_udp_file(net_udp_file: &Path) -> Result<Vec<UdpStats>> {
+    match fs::read_to_string(net_udp_file) {
+        Err(e) if e.kind() == ErrorKind::NotFound => Ok(Vec::new()),
+        content => parse_udp_table(&content?),
+    }
+}
+
+fn parse_udp_table(content: &str) -> Result<Vec<UdpStats>> {
+    let mut output = Vec::new();
+    for line in content.lines().skip(1) {
+        let first_split: Vec<&str> = line.split_whitespace().collect();
+
+        if first_split.len() < 13 {
+            continue;
         }
+
+        let (local_ip, local_port) = parse_ip_address(first_split[1])?;
+        let (remote_ip, remote_port) = parse_ip_address(first_split[2])?;
+
+        let state = parse_hex_values(first_split[3])?;
+        let (tx_queue, rx_queue) = parse_hex_value_pairs(first_split[4])?;
+
+        let uid = first_split[7].parse::<u32>()?;
+        let inode = first_split[9].parse::<u64>()?;
+        let drops = first_split[12].parse::<u64>()?;
+
+        output.push(UdpStats {
+            local_ip,
+            local_port,
+            remote_ip,
+            remote_port,
+            state,
+            tx_queue,
+            rx_queue,
+            uid,
+            inode,
+            drops,
+        });
     }
     Ok(output)
 }
 
+// Maps socket inodes to the process holding them by walking `/proc/<pid>/fd`.
+// Processes whose fds we can't read (other users, without privilege) are skipped,
+// so their sockets stay unmapped.
+pub fn build_socket_process_index() -> HashMap<u64, ProcessInfo> {
+    let mut index = HashMap::new();
+    let std::result::Result::Ok(entries) = fs::read_dir("/proc") else {
+        return index;
+    };
+
+    for entry in entries.flatten() {
+        let Some(pid) = entry
+            .file_name()
+            .to_str()
+            .and_then(|x| x.parse::<u32>().ok())
+        else {
+            continue;
+        };
+        let std::result::Result::Ok(fds) = fs::read_dir(entry.path().join("fd")) else {
+            continue;
+        };
+
+        let mut process: Option<ProcessInfo> = None;
+        for fd in fds.flatten() {
+            let std::result::Result::Ok(target) = fs::read_link(fd.path()) else {
+                continue;
+            };
+            let Some(inode) = target
+                .to_str()
+                .and_then(|x| x.strip_prefix("socket:["))
+                .and_then(|x| x.strip_suffix(']'))
+                .and_then(|x| x.parse::<u64>().ok())
+            else {
+                continue;
+            };
+
+            let info = process.get_or_insert_with(|| read_process_info(pid));
+            index.entry(inode).or_insert_with(|| info.clone());
+        }
+    }
+    index
+}
+
+fn read_process_info(pid: u32) -> ProcessInfo {
+    let comm = fs::read_to_string(format!("/proc/{pid}/comm"))
+        .map(|x| x.trim().to_string())
+        .unwrap_or_default();
+    let cmdline = fs::read(format!("/proc/{pid}/cmdline"))
+        .map(|x| {
+            String::from_utf8_lossy(&x)
+                .split('\0')
+                .filter(|x| !x.is_empty())
+                .collect::<Vec<_>>()
+                .join(" ")
+        })
+        .unwrap_or_default();
+    ProcessInfo { pid, comm, cmdline }
+}
+
 pub fn extract_speed(data_str: &str) -> String {
     data_str
         .split("speed: ")
@@ -259,8 +452,15 @@ pub fn parse_speed(speed_str: &str, link_speed: Option<f64>) -> f64 {
     (speed_mbps / link).min(1.0)
 }
 
-pub fn format_ip(ip: &[u8; 4]) -> String {
-    format!("{}.{}.{}.{}", ip[0], ip[1], ip[2], ip[3])
+// IPv6 addresses use the compressed RFC 5952 form and are bracketed, e.g. `[2001:db8::1]:443`.
+pub fn format_ip_port(ip: &IpAddr, port: u16) -> String {
+    SocketAddr::new(*ip, port).to_string()
+}
+
+// Wildcard and loopback addresses never leave the host, including IPv4-mapped ones on dual-stack sockets.
+pub fn is_local_ip(ip: &IpAddr) -> bool {
+    let ip = ip.to_canonical();
+    ip.is_unspecified() || ip.is_loopback()
 }
 
 pub fn tcp_state_name(state: u64) -> &'static str {
@@ -280,6 +480,371 @@ pub fn tcp_state_name(state: u64) -> &'static str {
     }
 }
 
+// UDP has no handshake, the kernel only reports whether the socket is connected.
+pub fn udp_state_name(state: u64) -> &'static str {
+    match state {
+        0x01 => "ESTABLISHED",
+        0x07 => "UNCONN",
+        _ => "UNKNOWN",
+    }
+}
+
+// Per-socket throughput from the tcp_info byte counters of two consecutive samples.
+// bytes_received counts what the peer sent us, bytes_acked what it confirmed receiving. `samples`
+// holds (time, received, acked) per socket and is replaced by this tick's; a socket gets a delta
+// from its second sample on.
+pub fn flow_deltas(
+    samples: &mut HashMap<SocketKey, (f64, u64, u64)>,
+    now: f64,
+    tcp_stats: &[TcpStats],
+) -> HashMap<SocketKey, FlowDelta> {
+    let mut current = HashMap::new();
+    let mut deltas = HashMap::new();
+    for s in tcp_stats {
+        let Some(info) = &s.tcp_info else {
+            continue;
+        };
+        let key = s.socket_key();
+        current.insert(key, (now, info.bytes_received, info.bytes_acked));
+        let Some((then, prev_rx, prev_tx)) = samples.get(&key) else {
+            continue;
+        };
+        let dt = now - then;
+        if dt <= 0.0 {
+            continue;
+        }
+        let rx_bytes = info.bytes_received.saturating_sub(*prev_rx);
+        let tx_bytes = info.bytes_acked.saturating_sub(*prev_tx);
+        deltas.insert(
+            key,
+            FlowDelta {
+                rx_bytes,
+                tx_bytes,
+                rx_rate: rx_bytes as f64 / dt,
+                tx_rate: tx_bytes as f64 / dt,
+            },
+        );
+    }
+    *samples = current;
+    deltas
+}
+
+// Sums the socket deltas per owning process, nethogs style. Rates and connection counts are for
+// this tick, byte totals accumulate. Listening sockets and sockets whose owner we can't see are
+// left out. UDP has no per-socket byte counters, so only TCP is accounted.
+pub fn account_process_traffic(
+    traffic: &mut HashMap<u32, ProcessTraffic>,
+    tcp_stats: &[TcpStats],
+    deltas: &HashMap<SocketKey, FlowDelta>,
+    processes: &HashMap<u64, ProcessInfo>,
+) {
+    for t in traffic.values_mut() {
+        t.rx_rate = 0.0;
+        t.tx_rate = 0.0;
+        t.connections = 0;
+    }
+    for s in tcp_stats {
+        if s.inode == 0 || s.state_name() == "LISTEN" {
+            continue;
+        }
+        let Some(p) = processes.get(&s.inode) else {
+            continue;
+        };
+        let t = traffic.entry(p.pid).or_insert_with(|| ProcessTraffic {
+            pid: p.pid,
+            comm: p.comm.clone(),
+            uid: s.uid,
+            ..Default::default()
+        });
+        t.connections += 1;
+        if let Some(d) = deltas.get(&s.socket_key()) {
+            t.rx_rate += d.rx_rate;
+            t.tx_rate += d.tx_rate;
+            t.rx_bytes += d.rx_bytes;
+            t.tx_bytes += d.tx_bytes;
+        }
+    }
+}
+
+// Lifecycle events between two socket snapshots, each given as connection -> state name. A
+// connection missing from `prev` was opened, one missing from `current` was closed.
+pub fn diff_sockets(
+    prev: &HashMap<ConnectionKey, &'static str>,
+    current: &HashMap<ConnectionKey, &'static str>,
+) -> Vec<SocketEvent> {
+    let mut events: Vec<SocketEvent> = current
+        .iter()
+        .filter_map(|(key, state)| match prev.get(key) {
+            None => Some(SocketEvent::Opened(*key)),
+            Some(before) if before != state => Some(SocketEvent::StateChanged(*key, before, state)),
+            Some(_) => None,
+        })
+        .collect();
+    events.extend(
+        prev.keys()
+            .filter(|key| !current.contains_key(key))
+            .map(|key| SocketEvent::Closed(*key)),
+    );
+    events
+}
+
+// /proc/net/snmp and /proc/net/netstat come in line pairs: `Tcp: <names...>` then
+// `Tcp: <values...>`. A values line only counts when its prefix repeats the names line before it,
+// so one stray line can't shift every section after it. Values that don't fit a u64 (Tcp MaxConn
+// is -1) are skipped.
+fn parse_keyed_counters(data: &str) -> HashMap<String, HashMap<String, u64>> {
+    let mut sections = HashMap::new();
+    let mut header: Option<(&str, &str)> = None;
+
+    for line in data.lines() {
+        let Some((section, rest)) = line.split_once(':') else {
+            header = None;
+            continue;
+        };
+        let Some((_, names)) = header.filter(|(name, _)| *name == section) else {
+            header = Some((section, rest));
+            continue;
+        };
+        let counters: HashMap<String, u64> = names
+            .split_whitespace()
+            .zip(rest.split_whitespace())
+            .filter_map(|(name, value)| value.parse().ok().map(|v| (name.to_string(), v)))
+            .collect();
+        sections.insert(section.to_string(), counters);
+        header = None;
+    }
+    sections
+}
+
+pub fn parse_proto_stats() -> Result<ProtoStats> {
+    let snmp = fs::read_to_string("/proc/net/snmp")?;
+    // netstat is optional in some containers; the snmp counters are still useful on their own.
+    let netstat = fs::read_to_string("/proc/net/netstat").unwrap_or_default();
+    Ok(proto_stats(&snmp, &netstat))
+}
+
+fn proto_stats(snmp: &str, netstat: &str) -> ProtoStats {
+    let mut sections = parse_keyed_counters(snmp);
+    sections.extend(parse_keyed_counters(netstat));
+
+    let empty = HashMap::new();
+    let section = |name: &str| sections.get(name).unwrap_or(&empty);
+    ProtoStats {
+        ip: IpCounters::from_map(section("Ip")),
+        icmp: IcmpCounters::from_map(section("Icmp")),
+        tcp: TcpCounters::from_map(section("Tcp")),
+        udp: UdpCounters::from_map(section("Udp")),
+        tcp_ext: TcpExtCounters::from_map(section("TcpExt")),
+        ip_ext: IpExtCounters::from_map(section("IpExt")),
+    }
+}
+
+pub fn read_cpu_net_stats() -> Result<Vec<CpuNetStats>> {
+    let mut stats = parse_softnet_stat(&fs::read_to_string("/proc/net/softnet_stat")?);
+    let softirqs = parse_softirqs(&fs::read_to_string("/proc/softirqs").unwrap_or_default());
+    for cpu in &mut stats {
+        if let Some((rx, tx)) = softirqs.get(&cpu.cpu) {
+            cpu.net_rx = *rx;
+            cpu.net_tx = *tx;
+        }
+    }
+    Ok(stats)
+}
+
+// One line of hex words per online CPU: processed, dropped, time_squeeze, five unused,
+// cpu_collision, received_rps, flow_limit_count, then since 5.10 the backlog length and the
+// CPU id. Offline CPUs are skipped, so without the id the line number has to do.
+fn parse_softnet_stat(data: &str) -> Vec<CpuNetStats> {
+    data.lines()
+        .enumerate()
+        .filter_map(|(line, text)| {
+            let fields: Vec<u64> = text
+                .split_whitespace()
+                .map(|f| u64::from_str_radix(f, 16).ok())
+                .collect::<Option<_>>()?;
+            if fields.len() < 11 {
+                return None;
+            }
+            Some(CpuNetStats {
+                cpu: fields.get(12).map_or(line, |id| *id as usize),
+                processed: fields[0],
+                dropped: fields[1],
+                time_squeeze: fields[2],
+                received_rps: fields[9],
+                flow_limit: fields[10],
+                backlog: fields.get(11).copied(),
+                ..Default::default()
+            })
+        })
+        .collect()
+}
+
+// A "CPU0 CPU1 ..." header, then one row per softirq. Columns cover every possible CPU, online
+// or not, so they are keyed by the number in the header.
+fn parse_softirqs(data: &str) -> HashMap<usize, (u64, u64)> {
+    let mut lines = data.lines();
+    let Some(header) = lines.next() else {
+        return HashMap::new();
+    };
+    let cpus: Vec<usize> = header
+        .split_whitespace()
+        .filter_map(|c| c.strip_prefix("CPU")?.parse().ok())
+        .collect();
+
+    let mut counts: HashMap<usize, (u64, u64)> = HashMap::new();
+    for line in lines {
+        let Some((name, values)) = line.split_once(':') else {
+            continue;
+        };
+        let name = name.trim();
+        if name != "NET_RX" && name != "NET_TX" {
+            continue;
+        }
+        for (cpu, value) in cpus.iter().zip(values.split_whitespace()) {
+            let value = value.parse().unwrap_or(0);
+            let entry = counts.entry(*cpu).or_default();
+            if name == "NET_RX" {
+                entry.0 = value;
+            } else {
+                entry.1 = value;
+            }
+        }
+    }
+    counts
+}
+
+pub fn parse_proc_net_wireless() -> Result<Vec<WirelessStats>> {
+    Ok(parse_wireless(&fs::read_to_string("/proc/net/wireless")?))
+}
+
+// The kernel appends '.' to link/level/noise when they were updated since the last read.
+fn parse_wireless(data: &str) -> Vec<WirelessStats> {
+    data.lines()
+        .skip(2)
+        .filter_map(|line| {
+            let (name, rest) = line.split_once(':')?;
+            let fields: Vec<&str> = rest.split_whitespace().collect();
+            if fields.len() < 10 {
+                return None;
+            }
+            let float = |i: usize| fields[i].trim_end_matches('.').parse::<f64>().ok();
+            let int = |i: usize| fields[i].parse::<u64>().ok();
+
+            Some(WirelessStats {
+                name: name.trim().to_string(),
+                status: u16::from_str_radix(fields[0], 16).ok()?,
+                link: float(1)?,
+                level: float(2)?,
+                noise: float(3).filter(|n| *n != -256.0),
+                discarded_nwid: int(4)?,
+                discarded_crypt: int(5)?,
+                discarded_frag: int(6)?,
+                discarded_retry: int(7)?,
+                discarded_misc: int(8)?,
+                missed_beacon: int(9)?,
+            })
+        })
+        .collect()
+}
+
+pub fn parse_sockstat() -> Result<SockStat> {
+    let v4 = fs::read_to_string("/proc/net/sockstat")?;
+    let v6 = fs::read_to_string("/proc/net/sockstat6").unwrap_or_default();
+    let tcp_mem = fs::read_to_string("/proc/sys/net/ipv4/tcp_mem").unwrap_or_default();
+    Ok(sockstat(&v4, &v6, &tcp_mem))
+}
+
+fn sockstat(v4: &str, v6: &str, tcp_mem: &str) -> SockStat {
+    let mut counters: HashMap<(String, String), u64> = HashMap::new();
+
+    for line in v4.lines().chain(v6.lines()) {
+        let Some((proto, rest)) = line.split_once(':') else {
+            continue;
+        };
+        // TCP6 inuse and TCP inuse are separate sockets, so they add up.
+        let proto = proto.trim_end_matches('6');
+        let fields: Vec<&str> = rest.split_whitespace().collect();
+        for pair in fields.chunks(2) {
+            if let [key, value] = pair {
+                if let std::result::Result::Ok(value) = value.parse::<u64>() {
+                    *counters
+                        .entry((proto.to_string(), key.to_string()))
+                        .or_default() += value;
+                }
+            }
+        }
+    }
+
+    let get = |proto: &str, key: &str| {
+        counters
+            .get(&(proto.to_string(), key.to_string()))
+            .copied()
+            .unwrap_or(0)
+    };
+    let tcp_mem_limits: Vec<u64> = tcp_mem
+        .split_whitespace()
+        .filter_map(|x| x.parse().ok())
+        .collect();
+
+    SockStat {
+        tcp_inuse: get("TCP", "inuse"),
+        tcp_orphan: get("TCP", "orphan"),
+        tcp_tw: get("TCP", "tw"),
+        tcp_alloc: get("TCP", "alloc"),
+        tcp_mem: get("TCP", "mem"),
+        udp_inuse: get("UDP", "inuse"),
+        udp_mem: get("UDP", "mem"),
+        raw_inuse: get("RAW", "inuse"),
+        frag_inuse: get("FRAG", "inuse"),
+        frag_memory: get("FRAG", "memory"),
+        tcp_mem_limits: tcp_mem_limits.try_into().ok(),
+    }
+}
+
+pub fn page_size() -> u64 {
+    let size = unsafe { libc::sysconf(libc::_SC_PAGESIZE) };
+    if size > 0 {
+        size as u64
+    } else {
+        4096
+    }
+}
+
+// IF_OPER_* from RFC 2863, as reported in IFLA_OPERSTATE.
+pub fn operstate_name(state: u8) -> &'static str {
+    match state {
+        1 => "notpresent",
+        2 => "down",
+        3 => "lowerlayerdown",
+        4 => "testing",
+        5 => "dormant",
+        6 => "up",
+        _ => "unknown",
+    }
+}
+
+pub fn format_rtt(rtt_us: u32) -> String {
+    if rtt_us >= 1000 {
+        format!("{:.1}ms", rtt_us as f64 / 1000.0)
+    } else {
+        format!("{}us", rtt_us)
+    }
+}
+
+// Short age for the Recent panel: "850ms", "42s", "3m07s", "1h02m".
+pub fn format_duration(d: Duration) -> String {
+    let secs = d.as_secs();
+    if secs == 0 {
+        format!("{}ms", d.as_millis())
+    } else if secs < 60 {
+        format!("{}s", secs)
+    } else if secs < 3600 {
+        format!("{}m{:02}s", secs / 60, secs % 60)
+    } else {
+        format!("{}h{:02}m", secs / 3600, secs % 3600 / 60)
+    }
+}
+
 pub fn format_speed_mbps(mbps: f64) -> String {
     if mbps >= 1000.0 {
         format!("{:.2} GB/s", mbps / 1000.0)
@@ -314,9 +879,9 @@ static CONF_FILE: Lazy<PathBuf> = Lazy::new(|| HOME_DIR.to_path_buf().join(".rxt
 
 pub fn initialize_conf() -> Result<(), Error> {
     if !Path::new(&CONF_FILE.as_path()).exists() {
-        let theme = format!("Theme: Default\nInterface: default, 0, 0");
+        let theme = "Theme: Default\nInterface: default, 0, 0".to_string();
 
-        fs::write(&CONF_FILE.as_path(), theme)?;
+        fs::write(CONF_FILE.as_path(), theme)?;
     }
 
     Ok(())
@@ -324,7 +889,7 @@ pub fn initialize_conf() -> Result<(), Error> {
 // Fetches the theme from the conf, if found nothing then defaults to Default theme
 pub fn get_theme() -> Theme {
     let default_theme = Theme::default();
-    let mut file = match OpenOptions::new().read(true).open(&CONF_FILE.as_path()) {
+    let mut file = match OpenOptions::new().read(true).open(CONF_FILE.as_path()) {
         std::result::Result::Ok(f) => f,
         Err(_) => return default_theme,
     };
@@ -359,9 +924,10 @@ pub fn get_theme() -> Theme {
 pub fn save_theme(theme: &'static str) -> Result<(), Error> {
     let mut file = OpenOptions::new()
         .create(true)
+        .truncate(false)
         .read(true)
         .write(true)
-        .open(&CONF_FILE.as_path())?;
+        .open(CONF_FILE.as_path())?;
 
     let mut buf = String::new();
     file.read_to_string(&mut buf)?;
@@ -373,7 +939,7 @@ pub fn save_theme(theme: &'static str) -> Result<(), Error> {
             let new_theme = buf.replace(old_theme, &format!(" {}", theme));
 
             if old_theme != new_theme {
-                fs::write(&CONF_FILE.as_path(), new_theme)?;
+                fs::write(CONF_FILE.as_path(), new_theme)?;
                 break;
             }
         }
@@ -382,10 +948,11 @@ pub fn save_theme(theme: &'static str) -> Result<(), Error> {
 }
 
 // Option for user to write to the rxtx.conf file in `Interface: interface, rx_top_speed, tx_top_speed` format and this function will just get the information.
-pub fn get_interface_speed() -> HashMap<String, (f64, f64)> {
-    let mut map: HashMap<String, (f64, f64)> = HashMap::new();
+// A `-` leaves that direction to link speed detection.
+pub fn get_interface_speed() -> HashMap<String, (Option<f64>, Option<f64>)> {
+    let mut map = HashMap::new();
 
-    let mut file = match OpenOptions::new().read(true).open(&CONF_FILE.as_path()) {
+    let mut file = match OpenOptions::new().read(true).open(CONF_FILE.as_path()) {
         std::result::Result::Ok(f) => f,
         Err(_) => return map,
     };
@@ -401,10 +968,10 @@ pub fn get_interface_speed() -> HashMap<String, (f64, f64)> {
 
             if parts.len() == 3 {
                 let interface_name = parts[0].trim();
-                let rx_speed = parts[1].trim().parse::<f64>().unwrap_or(0.0);
-                let tx_speed = parts[2].trim().parse::<f64>().unwrap_or(0.0);
+                let speed = |s: &str| s.trim().parse::<f64>().ok().filter(|s| *s > 0.0);
+                let (rx_speed, tx_speed) = (speed(parts[1]), speed(parts[2]));
 
-                if !interface_name.is_empty() && rx_speed > 0.0 && tx_speed > 0.0 {
+                if !interface_name.is_empty() && (rx_speed.is_some() || tx_speed.is_some()) {
                     map.insert(interface_name.to_string(), (rx_speed, tx_speed));
                 }
             } else {
@@ -415,9 +982,800 @@ pub fn get_interface_speed() -> HashMap<String, (f64, f64)> {
     map
 }
 
+// Ethernet drivers expose the negotiated speed in sysfs (-1 or EINVAL while the link is down or
+// for virtual devices). Wireless interfaces don't, so fall back to the current bitrate.
+pub fn detect_link_speed(interface: &str) -> Option<LinkSpeed> {
+    let base = Path::new("/sys/class/net").join(interface);
+
+    let speed = fs::read_to_string(base.join("speed"))
+        .ok()
+        .and_then(|s| s.trim().parse::<i64>().ok())
+        .filter(|s| *s > 0 && *s < u32::MAX as i64);
+
+    if let Some(mbps) = speed {
+        let duplex = fs::read_to_string(base.join("duplex"))
+            .ok()
+            .map(|d| d.trim().to_string())
+            .filter(|d| d != "unknown");
+        return Some(LinkSpeed {
+            mbps: mbps as f64,
+            duplex,
+            wireless: false,
+        });
+    }
+
+    if base.join("wireless").exists() {
+        return wireless_bitrate(interface).map(|bps| LinkSpeed {
+            mbps: bps as f64 / 1_000_000.0,
+            duplex: None,
+            wireless: true,
+        });
+    }
+    None
+}
+
+// (ifindex, iflink) from sysfs; they differ for veths and other stacked devices.
+pub fn read_sysfs_iflink(interface: &str) -> Option<(u32, u32)> {
+    let base = Path::new("/sys/class/net").join(interface);
+    let read = |file: &str| -> Option<u32> {
+        fs::read_to_string(base.join(file))
+            .ok()?
+            .trim()
+            .parse()
+            .ok()
+    };
+    Some((read("ifindex")?, read("iflink")?))
+}
+
+pub fn parse_proc_interrupts() -> Result<(Vec<usize>, Vec<IrqStats>)> {
+    Ok(parse_interrupts(&fs::read_to_string("/proc/interrupts")?))
+}
+
+// A header of online CPUs, then "<irq>: <count per CPU> <chip...> <hwirq-trigger> <actions>".
+// x86 writes the trigger as "5-edge", GIC as a separate "Edge"/"Level"; lines like NMI: and
+// LOC: aren't device interrupts and are skipped.
+fn parse_interrupts(data: &str) -> (Vec<usize>, Vec<IrqStats>) {
+    let mut lines = data.lines();
+    let cpus: Vec<usize> = lines
+        .next()
+        .unwrap_or_default()
+        .split_whitespace()
+        .filter_map(|c| c.strip_prefix("CPU")?.parse().ok())
+        .collect();
+
+    let irqs = lines
+        .filter_map(|line| {
+            let (irq, rest) = line.split_once(':')?;
+            let irq = irq.trim().parse().ok()?;
+            let fields: Vec<&str> = rest.split_whitespace().collect();
+            let counts: Vec<u64> = fields
+                .iter()
+                .take(cpus.len())
+                .map_while(|f| f.parse().ok())
+                .collect();
+            let rest = &fields[counts.len()..];
+            let trigger = rest.iter().position(|f| {
+                let f = f.to_lowercase();
+                f.ends_with("edge") || f.ends_with("level") || f.ends_with("fasteoi")
+            });
+            let (chip, actions) = match trigger {
+                Some(i) => (&rest[..i], &rest[i + 1..]),
+                None => (&rest[..rest.len().min(1)], &rest[rest.len().min(1)..]),
+            };
+            Some(IrqStats {
+                irq,
+                counts,
+                chip: chip.join(" "),
+                actions: actions.join(" "),
+            })
+        })
+        .collect();
+    (cpus, irqs)
+}
+
+// An interface's IRQs: the MSI vectors of its PCI function (for virtio NICs, of the PCI device
+// the virtio device sits on), plus handlers named after the interface or its virtio device.
+// Without `sysfs`, when it describes another namespace, only handler names are matched.
+pub fn interface_irqs<'a>(interface: &str, irqs: &'a [IrqStats], sysfs: bool) -> Vec<&'a IrqStats> {
+    let mut names = vec![interface.to_string()];
+    let mut msi = HashSet::new();
+    let device = fs::canonicalize(Path::new("/sys/class/net").join(interface).join("device"));
+    if let (true, std::result::Result::Ok(device)) = (sysfs, device) {
+        let mut pci = device.as_path();
+        if let Some(base) = device.file_name().and_then(|b| b.to_str()) {
+            if base.starts_with("virtio") {
+                names.push(base.to_string());
+                pci = device.parent().unwrap_or(pci);
+            }
+        }
+        if let std::result::Result::Ok(entries) = fs::read_dir(pci.join("msi_irqs")) {
+            msi.extend(
+                entries
+                    .flatten()
+                    .filter_map(|e| e.file_name().to_str()?.parse::<u32>().ok()),
+            );
+        }
+    }
+
+    irqs.iter()
+        .filter(|irq| {
+            msi.contains(&irq.irq)
+                || irq
+                    .actions
+                    .split(|c: char| !c.is_alphanumeric())
+                    .any(|word| names.iter().any(|n| n == word))
+        })
+        .collect()
+}
+
+// Where the IRQ is actually delivered; smp_affinity_list is only what was asked for.
+pub fn irq_affinity(irq: u32) -> Option<String> {
+    ["effective_affinity_list", "smp_affinity_list"]
+        .iter()
+        .find_map(|file| {
+            let list = fs::read_to_string(format!("/proc/irq/{}/{}", irq, file)).ok()?;
+            Some(list.trim().to_string()).filter(|l| !l.is_empty())
+        })
+}
+
+pub fn interface_driver(interface: &str) -> Option<String> {
+    let link = fs::read_link(
+        Path::new("/sys/class/net")
+            .join(interface)
+            .join("device/driver"),
+    )
+    .ok()?;
+    Some(link.file_name()?.to_string_lossy().to_string())
+}
+
+// rx-N and tx-N in queue order, with RPS/XPS masks, byte queue limits and the per-queue
+// counters from ethtool.
+pub fn read_net_queues(interface: &str) -> Vec<NetQueue> {
+    let base = Path::new("/sys/class/net").join(interface).join("queues");
+    let std::result::Result::Ok(entries) = fs::read_dir(&base) else {
+        return Vec::new();
+    };
+    let counters = queue_counters(&ethtool_stats(interface).unwrap_or_default());
+    let read = |queue: &str, file: &str| {
+        fs::read_to_string(base.join(queue).join(file))
+            .ok()
+            .map(|s| s.trim().to_string())
+    };
+
+    let mut queues: Vec<NetQueue> = entries
+        .flatten()
+        .filter_map(|e| e.file_name().to_str().map(|s| s.to_string()))
+        .filter(|name| name.starts_with("rx-") || name.starts_with("tx-"))
+        .map(|name| {
+            let value = |file: &str| read(&name, file).and_then(|v| v.parse::<u64>().ok());
+            let mask = if name.starts_with("rx-") {
+                "rps_cpus"
+            } else {
+                "xps_cpus"
+            };
+            NetQueue {
+                cpus: read(&name, mask).map(|m| parse_cpu_mask(&m)),
+                rps_flow_cnt: value("rps_flow_cnt"),
+                bql_inflight: value("byte_queue_limits/inflight"),
+                bql_limit: value("byte_queue_limits/limit"),
+                tx_maxrate: value("tx_maxrate").filter(|r| *r > 0),
+                counters: counters.get(&name).cloned().unwrap_or_default(),
+                name,
+            }
+        })
+        .collect();
+    queues.sort_by_key(|q| {
+        let (kind, n) = q.name.split_once('-').unwrap_or_default();
+        (kind.to_string(), n.parse::<u32>().unwrap_or(0))
+    });
+    queues
+}
+
+// Drivers name per-queue ethtool counters differently: rx_queue_0_packets (virtio, ixgbe, ena),
+// rx0_bytes (mlx5, newer virtio), rx-0.packets (i40e). Only plain packets/bytes/drops count;
+// rx0_xdp_packets and the like are skipped.
+fn queue_counters(stats: &[(String, u64)]) -> HashMap<String, QueueCounters> {
+    let mut queues: HashMap<String, QueueCounters> = HashMap::new();
+    for (name, value) in stats {
+        let name = name.to_lowercase();
+        let Some(direction) = ["rx", "tx"].into_iter().find(|d| name.starts_with(d)) else {
+            continue;
+        };
+        let rest = name[2..].trim_start_matches(['_', '-', '.']);
+        let rest = rest.strip_prefix("queue").unwrap_or(rest);
+        let rest = rest.trim_start_matches(['_', '-', '.']);
+        let digits = rest.chars().take_while(|c| c.is_ascii_digit()).count();
+        if digits == 0 {
+            continue;
+        }
+        let queue = format!("{}-{}", direction, &rest[..digits]);
+        let counters = queues.entry(queue).or_default();
+        match rest[digits..].trim_start_matches(['_', '-', '.']) {
+            "packets" => counters.packets = Some(*value),
+            "bytes" => counters.bytes = Some(*value),
+            "drops" => counters.drops = Some(*value),
+            _ => {}
+        }
+    }
+    queues
+}
+
+// "00000000,0000000f" -> [0, 1, 2, 3]; 32-bit words, most significant first.
+pub fn parse_cpu_mask(mask: &str) -> Vec<usize> {
+    let hex: Vec<u32> = mask
+        .trim()
+        .chars()
+        .filter(|c| *c != ',')
+        .filter_map(|c| c.to_digit(16))
+        .collect();
+    let mut cpus = Vec::new();
+    for (i, digit) in hex.iter().rev().enumerate() {
+        for bit in 0..4 {
+            if digit & (1 << bit) != 0 {
+                cpus.push(i * 4 + bit);
+            }
+        }
+    }
+    cpus
+}
+
+// [0, 1, 2, 3, 8] -> "0-3,8", like the kernel's *_list files.
+pub fn format_cpu_list(cpus: &[usize]) -> String {
+    let mut ranges: Vec<String> = Vec::new();
+    let mut i = 0;
+    while i < cpus.len() {
+        let start = cpus[i];
+        while i + 1 < cpus.len() && cpus[i + 1] == cpus[i] + 1 {
+            i += 1;
+        }
+        ranges.push(if cpus[i] == start {
+            start.to_string()
+        } else {
+            format!("{}-{}", start, cpus[i])
+        });
+        i += 1;
+    }
+    ranges.join(",")
+}
+
+// ATF_* flags from linux/if_arp.h.
+const ATF_COM: u32 = 0x02;
+const ATF_PERM: u32 = 0x04;
+const ATF_PUBL: u32 = 0x08;
+
+pub fn parse_proc_net_arp() -> Result<Vec<Neighbor>> {
+    Ok(parse_arp(&fs::read_to_string("/proc/net/arp")?))
+}
+
+// IP address, HW type, Flags, HW address, Mask, Device. The table has no NUD state, so the
+// state is derived from the flags the way `arp -n` shows them.
+fn parse_arp(data: &str) -> Vec<Neighbor> {
+    data.lines()
+        .skip(1)
+        .filter_map(|line| {
+            let fields: Vec<&str> = line.split_whitespace().collect();
+            if fields.len() < 6 {
+                return None;
+            }
+            let flags = u32::from_str_radix(fields[2].trim_start_matches("0x"), 16).ok()?;
+            // Published (proxy ARP) entries carry our own MAC and never get ATF_COM.
+            let complete = flags & (ATF_COM | ATF_PUBL) != 0;
+            let state = if !complete {
+                "INCOMPLETE"
+            } else if flags & ATF_PERM != 0 {
+                "PERMANENT"
+            } else if flags & ATF_COM != 0 {
+                "COMPLETE"
+            } else {
+                "PROXY"
+            };
+            let letters = [(ATF_COM, 'C'), (ATF_PERM, 'M'), (ATF_PUBL, 'P')]
+                .iter()
+                .filter(|(bit, _)| flags & bit != 0)
+                .map(|(_, c)| *c)
+                .collect();
+
+            Some(Neighbor {
+                ip: fields[0].parse().ok()?,
+                mac: complete.then(|| fields[3].to_string()),
+                device: fields[5].to_string(),
+                flags: letters,
+                state,
+                incomplete: !complete,
+            })
+        })
+        .collect()
+}
+
+// NUD_* neighbour states (linux/neighbour.h); one bit is set at a time.
+pub fn neigh_state_name(state: u16) -> &'static str {
+    match state {
+        0x01 => "INCOMPLETE",
+        0x02 => "REACHABLE",
+        0x04 => "STALE",
+        0x08 => "DELAY",
+        0x10 => "PROBE",
+        0x20 => "FAILED",
+        0x40 => "NOARP",
+        0x80 => "PERMANENT",
+        _ => "NONE",
+    }
+}
+
+pub fn parse_proc_net_nf_conntrack() -> Result<Vec<ConntrackEntry>> {
+    Ok(parse_nf_conntrack(&fs::read_to_string(
+        "/proc/net/nf_conntrack",
+    )?))
+}
+
+// "ipv4 2 tcp 6 <timeout> [state] src= dst= sport= dport= [packets= bytes=] [UNREPLIED]
+// src= dst= ... [ASSURED] mark= zone= use=". The second src= starts the reply tuple; ICMP
+// has type/code/id instead of ports, and the counters are missing without accounting.
+fn parse_nf_conntrack(data: &str) -> Vec<ConntrackEntry> {
+    data.lines()
+        .filter_map(|line| {
+            let fields: Vec<&str> = line.split_whitespace().collect();
+            if fields.len() < 5 {
+                return None;
+            }
+
+            let mut state = None;
+            let mut tuples: Vec<HashMap<&str, &str>> = Vec::new();
+            let mut assured = false;
+            let mut unreplied = false;
+            let mut mark = 0;
+            for field in &fields[5..] {
+                match field.split_once('=') {
+                    Some(("src", value)) if tuples.len() < 2 => {
+                        tuples.push(HashMap::from([("src", value)]));
+                    }
+                    Some(("mark", value)) => mark = value.parse().unwrap_or(0),
+                    Some((key, value)) => {
+                        if let Some(tuple) = tuples.last_mut() {
+                            tuple.entry(key).or_insert(value);
+                        }
+                    }
+                    None if *field == "[ASSURED]" => assured = true,
+                    None if *field == "[UNREPLIED]" => unreplied = true,
+                    None if tuples.is_empty() => state = Some(field.to_string()),
+                    None => {}
+                }
+            }
+            let [original, reply] = <[HashMap<&str, &str>; 2]>::try_from(tuples).ok()?;
+
+            let tuple = |t: &HashMap<&str, &str>| {
+                Some(ConntrackTuple {
+                    src: t.get("src")?.parse().ok()?,
+                    dst: t.get("dst")?.parse().ok()?,
+                    sport: t.get("sport").and_then(|p| p.parse().ok()),
+                    dport: t.get("dport").and_then(|p| p.parse().ok()),
+                })
+            };
+            let counter =
+                |t: &HashMap<&str, &str>, key: &str| t.get(key).and_then(|v| v.parse().ok());
+
+            Some(ConntrackEntry {
+                protocol: fields[2].to_string(),
+                state,
+                timeout: fields[4].parse().ok()?,
+                orig_packets: counter(&original, "packets"),
+                orig_bytes: counter(&original, "bytes"),
+                reply_packets: counter(&reply, "packets"),
+                reply_bytes: counter(&reply, "bytes"),
+                original: tuple(&original)?,
+                reply: tuple(&reply)?,
+                assured,
+                unreplied,
+                mark,
+            })
+        })
+        .collect()
+}
+
+// Current entries against net.netfilter.nf_conntrack_max; None when conntrack isn't loaded.
+pub fn read_conntrack_usage() -> Option<(u64, u64)> {
+    let read = |name: &str| -> Option<u64> {
+        fs::read_to_string(format!("/proc/sys/net/netfilter/{}", name))
+            .ok()?
+            .trim()
+            .parse()
+            .ok()
+    };
+    Some((read("nf_conntrack_count")?, read("nf_conntrack_max")?))
+}
+
+// TCP_CONNTRACK_* states (linux/netfilter/nf_conntrack_tcp.h), named as in /proc/net/nf_conntrack.
+pub fn conntrack_tcp_state_name(state: u8) -> &'static str {
+    match state {
+        1 => "SYN_SENT",
+        2 => "SYN_RECV",
+        3 => "ESTABLISHED",
+        4 => "FIN_WAIT",
+        5 => "CLOSE_WAIT",
+        6 => "LAST_ACK",
+        7 => "TIME_WAIT",
+        8 => "CLOSE",
+        9 => "SYN_SENT2",
+        _ => "NONE",
+    }
+}
+
+// Route flags shared by both files (linux/route.h, linux/ipv6_route.h).
+const RTF_UP: u32 = 0x0001;
+const RTF_GATEWAY: u32 = 0x0002;
+const RTF_HOST: u32 = 0x0004;
+const RTF_REJECT: u32 = 0x0200;
+const RTF_CACHE: u32 = 0x0100_0000;
+const RTF_LOCAL: u32 = 0x8000_0000;
+
+pub fn parse_proc_net_route() -> Result<Vec<Route>> {
+    let mut routes = parse_route_v4(&fs::read_to_string("/proc/net/route")?);
+    // No IPv6 at all when the module is disabled; that's not an error.
+    if let std::result::Result::Ok(data) = fs::read_to_string("/proc/net/ipv6_route") {
+        routes.extend(parse_route_v6(&data));
+    }
+    Ok(routes)
+}
+
+// Iface Destination Gateway Flags RefCnt Use Metric Mask ...; addresses are hex words in host
+// byte order, like /proc/net/tcp.
+fn parse_route_v4(data: &str) -> Vec<Route> {
+    data.lines()
+        .skip(1)
+        .filter_map(|line| {
+            let fields: Vec<&str> = line.split_whitespace().collect();
+            if fields.len() < 8 {
+                return None;
+            }
+            let addr = |hex: &str| -> Option<u32> { u32::from_str_radix(hex, 16).ok() };
+            let gateway = addr(fields[2])?;
+            Some(Route {
+                destination: IpAddr::V4(Ipv4Addr::from(addr(fields[1])?.to_ne_bytes())),
+                prefix_len: addr(fields[7])?.count_ones() as u8,
+                gateway: (gateway != 0).then(|| IpAddr::V4(Ipv4Addr::from(gateway.to_ne_bytes()))),
+                interface: fields[0].to_string(),
+                metric: fields[6].parse().ok()?,
+                flags: addr(fields[3])?,
+            })
+        })
+        .filter(|r| r.flags & RTF_UP != 0)
+        .collect()
+}
+
+// dest plen src plen gateway metric refcnt use flags iface; addresses are plain big-endian hex.
+fn parse_route_v6(data: &str) -> Vec<Route> {
+    data.lines()
+        .filter_map(|line| {
+            let fields: Vec<&str> = line.split_whitespace().collect();
+            if fields.len() < 10 {
+                return None;
+            }
+            let addr = |hex: &str| -> Option<Ipv6Addr> {
+                u128::from_str_radix(hex, 16).ok().map(Ipv6Addr::from)
+            };
+            let gateway = addr(fields[4])?;
+            Some(Route {
+                destination: IpAddr::V6(addr(fields[0])?),
+                prefix_len: u8::from_str_radix(fields[1], 16).ok()?,
+                gateway: (!gateway.is_unspecified()).then_some(IpAddr::V6(gateway)),
+                interface: fields[9].to_string(),
+                metric: u32::from_str_radix(fields[5], 16).ok()?,
+                flags: u32::from_str_radix(fields[8], 16).ok()?,
+            })
+        })
+        .filter(|r| r.flags & RTF_UP != 0 && r.flags & RTF_CACHE == 0)
+        .collect()
+}
+
+// `route -n` style flags: U(p), G(ateway), H(ost), L(ocal), ! (reject).
+pub fn route_flags(flags: u32) -> String {
+    [
+        (RTF_UP, 'U'),
+        (RTF_GATEWAY, 'G'),
+        (RTF_HOST, 'H'),
+        (RTF_LOCAL, 'L'),
+        (RTF_REJECT, '!'),
+    ]
+    .iter()
+    .filter(|(bit, _)| flags & bit != 0)
+    .map(|(_, c)| *c)
+    .collect()
+}
+
+// Longest-prefix match, lowest metric on ties. Reject routes (unreachable, blackhole) never
+// carry traffic, so they don't count as an egress. This ignores policy routing (`ip rule`).
+pub fn route_lookup<'a>(routes: &'a [Route], ip: &IpAddr) -> Option<&'a Route> {
+    let ip = ip.to_canonical();
+    routes
+        .iter()
+        .filter(|r| r.flags & RTF_REJECT == 0)
+        .filter(|r| match (r.destination, ip) {
+            (IpAddr::V4(dest), IpAddr::V4(ip)) => prefix_matches(
+                u32::from(dest) as u128,
+                u32::from(ip) as u128,
+                32,
+                r.prefix_len,
+            ),
+            (IpAddr::V6(dest), IpAddr::V6(ip)) => {
+                prefix_matches(u128::from(dest), u128::from(ip), 128, r.prefix_len)
+            }
+            _ => false,
+        })
+        .max_by_key(|r| (r.prefix_len, std::cmp::Reverse(r.metric)))
+}
+
+fn prefix_matches(dest: u128, ip: u128, bits: u8, prefix_len: u8) -> bool {
+    let prefix_len = prefix_len.min(bits);
+    if prefix_len == 0 {
+        return true;
+    }
+    let shift = bits - prefix_len;
+    dest >> shift == ip >> shift
+}
+
+// Maps each stacked interface to its parent: bridge ports and bond slaves to their master,
+// VLANs to the device they're on. sysfs only describes the namespace it was mounted in, so
+// `sysfs` is off elsewhere and rtnetlink's master/link attributes have to do.
+pub fn read_topology(stats: &[NetworkStats], sysfs: bool) -> HashMap<String, String> {
+    let names: HashSet<&str> = stats.iter().map(|s| s.name.as_str()).collect();
+    let by_index: HashMap<u32, &str> = stats
+        .iter()
+        .filter_map(|s| Some((s.link.as_ref()?.ifindex, s.name.as_str())))
+        .collect();
+    let mut parents = HashMap::new();
+
+    for stat in stats {
+        let Some(link) = &stat.link else {
+            continue;
+        };
+        if let Some(master) = link.master.and_then(|i| by_index.get(&i)) {
+            parents.insert(stat.name.clone(), master.to_string());
+        } else if link.kind.as_deref() == Some("vlan") {
+            if let Some(lower) = link.link.and_then(|i| by_index.get(&i)) {
+                parents.insert(stat.name.clone(), lower.to_string());
+            }
+        }
+    }
+
+    if sysfs {
+        let base = Path::new("/sys/class/net");
+        let list = |path: PathBuf| -> Vec<String> {
+            fs::read_dir(path)
+                .map(|entries| {
+                    entries
+                        .flatten()
+                        .map(|e| e.file_name().to_string_lossy().to_string())
+                        .collect()
+                })
+                .unwrap_or_default()
+        };
+        for name in &names {
+            let dir = base.join(name);
+            if let Some(master) = fs::read_link(dir.join("master"))
+                .ok()
+                .and_then(|m| Some(m.file_name()?.to_string_lossy().to_string()))
+            {
+                parents.insert(name.to_string(), master);
+            }
+            for port in list(dir.join("brif")) {
+                parents.insert(port, name.to_string());
+            }
+            let slaves = fs::read_to_string(dir.join("bonding/slaves")).unwrap_or_default();
+            for slave in slaves.split_whitespace() {
+                parents.insert(slave.to_string(), name.to_string());
+            }
+        }
+    }
+
+    // /proc/net follows the namespace, so VLANs resolve everywhere even without rtnetlink.
+    if let std::result::Result::Ok(config) = fs::read_to_string("/proc/net/vlan/config") {
+        parents.extend(parse_vlan_config(&config));
+    }
+
+    parents.retain(|child, parent| {
+        child != parent && names.contains(child.as_str()) && names.contains(parent.as_str())
+    });
+    parents
+}
+
+// "eth0.100       | 100  | eth0", below a two-line header.
+fn parse_vlan_config(data: &str) -> Vec<(String, String)> {
+    data.lines()
+        .skip(2)
+        .filter_map(|line| {
+            let fields: Vec<&str> = line.split('|').map(str::trim).collect();
+            match fields.as_slice() {
+                [vlan, _, parent] if !vlan.is_empty() && !parent.is_empty() => {
+                    Some((vlan.to_string(), parent.to_string()))
+                }
+                _ => None,
+            }
+        })
+        .collect()
+}
+
+pub fn parse_proc_net_bonding() -> HashMap<String, BondInfo> {
+    fs::read_dir("/proc/net/bonding")
+        .map(|entries| {
+            entries
+                .flatten()
+                .filter_map(|e| {
+                    let data = fs::read_to_string(e.path()).ok()?;
+                    Some((
+                        e.file_name().to_string_lossy().to_string(),
+                        parse_bonding(&data),
+                    ))
+                })
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+// Bond-wide settings come first, then a "Slave Interface:" block per slave. 802.3ad adds LACP
+// detail lines to both; only the first MII Status/Speed/Link Failure Count of a block counts.
+fn parse_bonding(data: &str) -> BondInfo {
+    let mut bond = BondInfo::default();
+
+    for line in data.lines() {
+        let Some((key, value)) = line.split_once(':') else {
+            continue;
+        };
+        let (key, value) = (key.trim(), value.trim());
+
+        if key == "Slave Interface" {
+            bond.slaves.push(BondSlave {
+                name: value.to_string(),
+                ..BondSlave::default()
+            });
+            continue;
+        }
+        match bond.slaves.last_mut() {
+            None => match key {
+                "Bonding Mode" => bond.mode = value.to_string(),
+                "Currently Active Slave" if value != "None" => {
+                    bond.active_slave = Some(value.to_string())
+                }
+                "MII Status" if bond.mii_status.is_empty() => bond.mii_status = value.to_string(),
+                _ => {}
+            },
+            Some(slave) => match key {
+                "MII Status" if slave.mii_status.is_empty() => slave.mii_status = value.to_string(),
+                "Speed" if slave.speed.is_none() && value != "Unknown" => {
+                    slave.speed = Some(value.to_string())
+                }
+                "Link Failure Count" => {
+                    slave.link_failures = value.parse().unwrap_or(slave.link_failures)
+                }
+                _ => {}
+            },
+        }
+    }
+    bond
+}
+
+// Finds the container a process belongs to from its /proc/<pid>/cgroup. Covers the systemd
+// (`docker-<id>.scope`) and cgroupfs (`/docker/<id>`) layouts, on cgroup v1 and v2 alike.
+pub fn parse_cgroup_container(cgroup: &str) -> Option<Container> {
+    let container = |runtime: &'static str, id: &str| Container {
+        runtime,
+        id: id.to_string(),
+        name: None,
+    };
+
+    for line in cgroup.lines() {
+        let Some(path) = line.splitn(3, ':').nth(2) else {
+            continue;
+        };
+        let parts: Vec<&str> = path.split('/').filter(|p| !p.is_empty()).collect();
+
+        for (i, part) in parts.iter().enumerate().rev() {
+            if let Some(scope) = part.strip_suffix(".scope") {
+                if let Some(id) = scope.strip_prefix("docker-") {
+                    return Some(container("docker", id));
+                }
+                // libpod-conmon-<id>.scope is podman's monitor process, not the container.
+                if let Some(id) = scope.strip_prefix("libpod-") {
+                    if !id.starts_with("conmon-") {
+                        return Some(container("podman", id));
+                    }
+                }
+                if let Some(id) = scope.strip_prefix("cri-containerd-") {
+                    return Some(container("containerd", id));
+                }
+                if let Some(machine) = scope.strip_prefix("machine-") {
+                    let machine = unescape_unit_name(machine);
+                    return Some(Container {
+                        name: Some(machine.clone()),
+                        ..container("nspawn", &machine)
+                    });
+                }
+            }
+            if let Some(machine) = part
+                .strip_prefix("systemd-nspawn@")
+                .and_then(|p| p.strip_suffix(".service"))
+            {
+                let machine = unescape_unit_name(machine);
+                return Some(Container {
+                    name: Some(machine.clone()),
+                    ..container("nspawn", &machine)
+                });
+            }
+
+            let is_id = part.len() == 64 && part.chars().all(|c| c.is_ascii_hexdigit());
+            if is_id && i > 0 {
+                if parts[i - 1] == "docker" {
+                    return Some(container("docker", part));
+                }
+                if parts[i - 1] == "libpod_parent" || parts[i - 1].starts_with("libpod-") {
+                    return Some(container("podman", part));
+                }
+                // Kubelet's cgroupfs driver: /kubepods/<qos>/pod<uid>/<container id>.
+                if parts[0] == "kubepods" || parts[0] == "kubepods.slice" {
+                    return Some(container("containerd", part));
+                }
+            }
+        }
+    }
+    None
+}
+
+// systemd escapes unit names: `machine-my\x2dbox.scope` is the machine `my-box`. Non-ASCII
+// characters are escaped one UTF-8 byte at a time.
+fn unescape_unit_name(name: &str) -> String {
+    let mut out = Vec::new();
+    let mut rest = name;
+    while let Some(pos) = rest.find("\\x") {
+        out.extend_from_slice(&rest.as_bytes()[..pos]);
+        let hex = rest.get(pos + 2..pos + 4);
+        match hex.and_then(|h| u8::from_str_radix(h, 16).ok()) {
+            Some(byte) => {
+                out.push(byte);
+                rest = &rest[pos + 4..];
+            }
+            None => {
+                out.extend_from_slice(b"\\x");
+                rest = &rest[pos + 2..];
+            }
+        }
+    }
+    out.extend_from_slice(rest.as_bytes());
+    String::from_utf8_lossy(&out).into_owned()
+}
+
+// Docker and podman keep the human-readable name in their state files; a plain substring
+// search is enough to pull it out without a JSON parser.
+pub fn lookup_container_name(runtime: &str, id: &str) -> Option<String> {
+    let after = |haystack: &str, needle: &str| -> Option<String> {
+        let start = haystack.find(needle)? + needle.len();
+        let end = haystack[start..].find('"')?;
+        Some(haystack[start..start + end].to_string())
+    };
+
+    match runtime {
+        "docker" => {
+            let config =
+                fs::read_to_string(format!("/var/lib/docker/containers/{}/config.v2.json", id))
+                    .ok()?;
+            after(&config, "\"Name\":\"/")
+        }
+        "podman" => {
+            let containers = fs::read_to_string(
+                "/var/lib/containers/storage/overlay-containers/containers.json",
+            )
+            .ok()?;
+            let entry = containers.find(&format!("\"id\":\"{}\"", id))?;
+            after(&containers[entry..], "\"names\":[\"")
+        }
+        _ => None,
+    }
+}
+
 // Makes Changes to `rxtx.conf` file - those information will be taken from the TUI.
-pub fn save_interface_speeds(map: &HashMap<String, (f64, f64)>) -> Result<(), Error> {
-    let data = fs::read_to_string(&CONF_FILE.as_path())?;
+pub fn save_interface_speeds(
+    map: &HashMap<String, (Option<f64>, Option<f64>)>,
+) -> Result<(), Error> {
+    let speed = |s: &Option<f64>| s.map_or("-".to_string(), |s| s.to_string());
+    let data = fs::read_to_string(CONF_FILE.as_path())?;
     let mut changed = false;
 
     let mut new_lines = Vec::new();
@@ -436,7 +1794,12 @@ pub fn save_interface_speeds(map: &HashMap<String, (f64, f64)>) -> Result<(), Er
                 {
                     seen.insert(new_name.to_lowercase());
                     changed = true;
-                    new_lines.push(format!("Interface: {}, {}, {}", new_name, rx, tx));
+                    new_lines.push(format!(
+                        "Interface: {}, {}, {}",
+                        new_name,
+                        speed(rx),
+                        speed(tx)
+                    ));
                     continue;
                 }
             }
@@ -449,13 +1812,712 @@ pub fn save_interface_speeds(map: &HashMap<String, (f64, f64)>) -> Result<(), Er
         let name_lc = name.to_lowercase();
         if !seen.contains(&name_lc) {
             changed = true;
-            new_lines.push(format!("Interface: {}, {}, {}", name, rx, tx));
+            new_lines.push(format!("Interface: {}, {}, {}", name, speed(rx), speed(tx)));
         }
     }
 
     if changed {
-        fs::write(&CONF_FILE.as_path(), new_lines.join("\n"))?;
+        fs::write(CONF_FILE.as_path(), new_lines.join("\n"))?;
     }
 
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn wireless_parses_every_interface() {
+        let stats = parse_wireless(include_str!("../tests/fixtures/proc_net_wireless"));
+
+        assert_eq!(stats.len(), 2);
+        assert_eq!(
+            stats[0],
+            WirelessStats {
+                name: "wlan0".to_string(),
+                status: 0,
+                link: 54.0,
+                level: -56.0,
+                noise: None,
+                discarded_nwid: 0,
+                discarded_crypt: 0,
+                discarded_frag: 0,
+                discarded_retry: 12,
+                discarded_misc: 150,
+                missed_beacon: 3,
+            }
+        );
+        assert_eq!(stats[1].name, "wlp2s0");
+        assert_eq!(stats[1].status, 1);
+        assert_eq!(stats[1].link, 70.0);
+        assert_eq!(stats[1].level, -40.0);
+        assert_eq!(stats[1].noise, Some(-95.0));
+        assert_eq!(stats[1].discarded_crypt, 2);
+        assert_eq!(stats[1].missed_beacon, 6);
+    }
+
+    #[test]
+    fn wireless_header_only_is_empty() {
+        let stats = parse_wireless(include_str!("../tests/fixtures/proc_net_wireless_empty"));
+        assert!(stats.is_empty());
+    }
+
+    #[test]
+    fn arp_flags_and_incomplete_entries() {
+        let neighbors = parse_arp(include_str!("../tests/fixtures/proc_net_arp"));
+
+        assert_eq!(neighbors.len(), 4);
+        assert_eq!(
+            neighbors[0],
+            Neighbor {
+                ip: "192.0.2.1".parse().unwrap(),
+                mac: Some("02:fc:00:00:00:05".to_string()),
+                device: "eth0".to_string(),
+                flags: "C".to_string(),
+                state: "COMPLETE",
+                incomplete: false,
+            }
+        );
+        assert!(neighbors[1].incomplete);
+        assert_eq!(neighbors[1].mac, None);
+        assert_eq!(neighbors[1].state, "INCOMPLETE");
+        assert_eq!(neighbors[2].state, "PERMANENT");
+        assert_eq!(neighbors[2].flags, "CM");
+        assert_eq!(neighbors[3].flags, "MP");
+        assert!(!neighbors[3].incomplete);
+        assert_eq!(neighbors[3].mac.as_deref(), Some("52:54:00:aa:bb:cc"));
+    }
+
+    #[test]
+    fn nf_conntrack_tuples_counters_and_nat() {
+        let entries = parse_nf_conntrack(include_str!("../tests/fixtures/proc_net_nf_conntrack"));
+
+        assert_eq!(entries.len(), 5);
+        let tcp = &entries[0];
+        assert_eq!(tcp.protocol, "tcp");
+        assert_eq!(tcp.state.as_deref(), Some("ESTABLISHED"));
+        assert_eq!(tcp.timeout, 431982);
+        assert_eq!(tcp.original.dport, Some(443));
+        assert_eq!(tcp.bytes(), Some(5230 + 48210));
+        assert!(tcp.assured && !tcp.unreplied);
+        assert!(tcp.snat() && !tcp.dnat());
+
+        let udp = &entries[1];
+        assert_eq!(udp.state, None);
+        assert!(udp.unreplied);
+        assert_eq!(udp.reply_packets, Some(0));
+
+        let icmp = &entries[2];
+        assert_eq!(icmp.original.sport, None);
+        assert!(!icmp.snat() && !icmp.dnat());
+
+        let dnat = &entries[3];
+        assert_eq!(dnat.packets(), None);
+        assert_eq!(dnat.mark, 16);
+        assert!(dnat.dnat() && !dnat.snat());
+
+        assert_eq!(
+            entries[4].original.src,
+            "2001:db8::1".parse::<IpAddr>().unwrap()
+        );
+    }
+
+    #[test]
+    fn softnet_stat_keys_cpus_by_id_and_merges_softirqs() {
+        let mut stats = parse_softnet_stat(include_str!("../tests/fixtures/proc_net_softnet_stat"));
+        let softirqs = parse_softirqs(include_str!("../tests/fixtures/proc_softirqs"));
+
+        assert_eq!(stats.len(), 3);
+        assert_eq!(
+            stats.iter().map(|s| s.cpu).collect::<Vec<_>>(),
+            vec![0, 1, 3]
+        );
+        assert_eq!(stats[0].processed, 0x0012d687);
+        assert_eq!(stats[1].dropped, 0x2a);
+        assert_eq!(stats[1].time_squeeze, 0x1f);
+        assert_eq!(stats[2].backlog, Some(3));
+
+        assert_eq!(softirqs.len(), 4);
+        assert_eq!(softirqs[&3], (321, 0));
+        for cpu in &mut stats {
+            (cpu.net_rx, cpu.net_tx) = softirqs[&cpu.cpu];
+        }
+        assert_eq!((stats[1].net_rx, stats[1].net_tx), (123456, 34));
+
+        // Before 5.10 there is no CPU id column.
+        let old = parse_softnet_stat("00000010 00000000 00000001 00000000 00000000 00000000 00000000 00000000 00000000 00000000 00000000\n00000020 00000000 00000000 00000000 00000000 00000000 00000000 00000000 00000000 00000000 00000000\n");
+        assert_eq!(old[1].cpu, 1);
+        assert_eq!(old[1].backlog, None);
+    }
+
+    #[test]
+    fn interrupts_split_counts_chip_and_actions() {
+        let (cpus, irqs) = parse_interrupts(include_str!("../tests/fixtures/proc_interrupts"));
+
+        assert_eq!(cpus, vec![0, 1, 2, 3]);
+        assert_eq!(irqs.len(), 8);
+        assert_eq!(
+            irqs[3],
+            IrqStats {
+                irq: 129,
+                counts: vec![982134, 3, 0, 0],
+                chip: "IR-PCI-MSI".to_string(),
+                actions: "i40e-eth2-TxRx-0".to_string(),
+            }
+        );
+        assert_eq!(irqs[2].actions, "enp2s0");
+        assert_eq!(irqs[6].chip, "PCI-MSIX-0000:00:04.0");
+        assert_eq!(irqs[6].actions, "virtio3-input.0, virtio3-output.0");
+        assert_eq!(irqs[7].chip, "GICv3 27");
+        assert_eq!(irqs[7].actions, "arch_timer");
+
+        // Matching by handler name; there is no sysfs device behind these in the test.
+        let eth2: Vec<u32> = interface_irqs("eth2", &irqs, false)
+            .iter()
+            .map(|i| i.irq)
+            .collect();
+        assert_eq!(eth2, vec![129, 130, 131]);
+    }
+
+    #[test]
+    fn cpu_masks_and_queue_counter_names() {
+        assert_eq!(parse_cpu_mask("00000000,0000010f"), vec![0, 1, 2, 3, 8]);
+        assert_eq!(parse_cpu_mask("0"), Vec::<usize>::new());
+        assert_eq!(format_cpu_list(&[0, 1, 2, 3, 8, 10, 11]), "0-3,8,10-11");
+
+        let stats: Vec<(String, u64)> = [
+            ("rx_queue_0_packets", 10),
+            ("rx_queue_0_bytes", 1000),
+            ("tx_queue_1_packets", 7),
+            ("rx1_bytes", 500),
+            ("rx1_xdp_packets", 99),
+            ("rx1_drops", 2),
+            ("tx-0.bytes", 42),
+            ("rx_packets", 1234),
+        ]
+        .iter()
+        .map(|(n, v)| (n.to_string(), *v))
+        .collect();
+        let queues = queue_counters(&stats);
+
+        assert_eq!(queues.len(), 4);
+        assert_eq!(
+            queues["rx-0"],
+            QueueCounters {
+                packets: Some(10),
+                bytes: Some(1000),
+                drops: None,
+            }
+        );
+        assert_eq!(queues["rx-1"].packets, None);
+        assert_eq!(queues["rx-1"].bytes, Some(500));
+        assert_eq!(queues["rx-1"].drops, Some(2));
+        assert_eq!(queues["tx-0"].bytes, Some(42));
+        assert_eq!(queues["tx-1"].packets, Some(7));
+    }
+
+    #[test]
+    fn sysfs_statistics_fill_per_cause_counters() {
+        let mut stats = NetworkStats {
+            name: "eth0".to_string(),
+            receive: Receive {
+                drop: 7,
+                frame: 5,
+                ..Default::default()
+            },
+            transmit: Transmit::default(),
+            link: None,
+        };
+        let counters: HashMap<String, u64> = [
+            ("rx_dropped", 4),
+            ("rx_missed_errors", 3),
+            ("rx_crc_errors", 5),
+            ("rx_nohandler", 9),
+            ("tx_window_errors", 1),
+        ]
+        .iter()
+        .map(|(n, v)| (n.to_string(), *v))
+        .collect();
+        apply_sysfs_statistics(&mut stats, &counters);
+
+        assert_eq!(stats.receive.drop, 7);
+        assert_eq!(stats.receive.missed_errors, Some(3));
+        assert_eq!(stats.receive.crc_errors, Some(5));
+        assert_eq!(stats.receive.length_errors, None);
+        assert_eq!(stats.receive.nohandler, Some(9));
+        assert_eq!(stats.transmit.window_errors, Some(1));
+
+        let shown: Vec<&str> = stats
+            .receive
+            .counters()
+            .iter()
+            .filter(|(_, v)| v.is_some())
+            .map(|(name, _)| name.trim())
+            .collect();
+        assert_eq!(
+            shown,
+            vec![
+                "Errors",
+                "Drops",
+                "dropped",
+                "missed",
+                "FIFO",
+                "Frame",
+                "crc",
+                "No handler",
+                "Compressed",
+                "Multicast"
+            ]
+        );
+    }
+
+    #[test]
+    fn tcp6_table_decodes_per_word_byte_order() {
+        let sockets = parse_tcp_table(include_str!("../tests/fixtures/proc_net_tcp6")).unwrap();
+
+        assert_eq!(sockets.len(), 4);
+        assert_eq!(
+            format_ip_port(&sockets[0].local_ip, sockets[0].local_port),
+            "[::]:22"
+        );
+        assert_eq!(sockets[0].state_name(), "LISTEN");
+
+        let https = &sockets[1];
+        assert_eq!(https.local_ip.to_string(), "2001:db8::2");
+        assert_eq!(https.local_port, 443);
+        assert_eq!(
+            format_ip_port(&https.remote_ip, https.remote_port),
+            "[2001:db8::1]:50000"
+        );
+        assert_eq!(https.uid, 33);
+
+        let link_local = &sockets[2];
+        assert_eq!(link_local.local_ip.to_string(), "fe80::a00:27ff:fe4e:66a1");
+        assert_eq!(link_local.remote_ip.to_string(), "fe80::1");
+
+        // Dual-stack sockets carry IPv4 peers as mapped addresses.
+        let mapped = &sockets[3];
+        assert_eq!(mapped.local_ip.to_string(), "::ffff:127.0.0.1");
+        assert_eq!(mapped.remote_ip.to_canonical().to_string(), "127.0.0.1");
+        assert_eq!(mapped.state_name(), "TIME_WAIT");
+    }
+
+    #[test]
+    fn udp6_table_keeps_queues_and_drops() {
+        let sockets = parse_udp_table(include_str!("../tests/fixtures/proc_net_udp6")).unwrap();
+
+        assert_eq!(sockets.len(), 3);
+        let mdns = &sockets[0];
+        assert_eq!(format_ip_port(&mdns.local_ip, mdns.local_port), "[::]:5353");
+        assert_eq!(mdns.state_name(), "UNCONN");
+        assert_eq!(mdns.drops, 0);
+
+        let dns = &sockets[1];
+        assert_eq!(
+            format_ip_port(&dns.local_ip, dns.local_port),
+            "[2001:db8::2]:53"
+        );
+        assert_eq!(
+            format_ip_port(&dns.remote_ip, dns.remote_port),
+            "[2001:db8::1]:40000"
+        );
+        assert_eq!(dns.state_name(), "ESTABLISHED");
+        assert_eq!((dns.tx_queue, dns.rx_queue), (0, 0x300));
+        assert_eq!((dns.uid, dns.inode), (101, 20874));
+        assert_eq!(dns.drops, 17);
+
+        let ntp = &sockets[2];
+        assert_eq!(ntp.local_ip.to_canonical().to_string(), "127.0.0.1");
+        assert_eq!(ntp.local_port, 123);
+        assert_eq!(ntp.drops, 4096);
+    }
+
+    #[test]
+    fn proto_stats_pair_names_with_values() {
+        let stats = proto_stats(
+            include_str!("../tests/fixtures/proc_net_snmp"),
+            include_str!("../tests/fixtures/proc_net_netstat"),
+        );
+
+        assert_eq!(stats.tcp.retrans_segs, 1834);
+        assert_eq!(stats.tcp.curr_estab, 12);
+        assert_eq!(stats.tcp.active_opens, 197);
+        assert_eq!(stats.udp.rcvbuf_errors, 57);
+        assert_eq!(stats.udp.no_ports, 316200);
+        assert_eq!(stats.ip.reasm_fails, 3);
+        assert_eq!(stats.icmp.in_dest_unreachs, 41);
+        assert_eq!(stats.tcp_ext.listen_overflows, 9);
+        assert_eq!(stats.tcp_ext.listen_drops, 11);
+        assert_eq!(stats.tcp_ext.syncookies_sent, 3);
+        assert_eq!(stats.ip_ext.in_octets, 339988443);
+    }
+
+    #[test]
+    fn keyed_counters_skip_unpaired_lines() {
+        // The Tcp names line lost its values; Udp must still line up.
+        let sections = parse_keyed_counters(
+            "Tcp: ActiveOpens RetransSegs\nUdp: InDatagrams RcvbufErrors\nUdp: 92 7\n",
+        );
+
+        assert!(!sections.contains_key("Tcp"));
+        assert_eq!(sections["Udp"]["RcvbufErrors"], 7);
+    }
+
+    #[test]
+    fn sockstat_adds_v6_and_weighs_tcp_mem() {
+        let stat = sockstat(
+            include_str!("../tests/fixtures/proc_net_sockstat"),
+            include_str!("../tests/fixtures/proc_net_sockstat6"),
+            include_str!("../tests/fixtures/proc_sys_net_ipv4_tcp_mem"),
+        );
+
+        assert_eq!(stat.tcp_inuse, 48);
+        assert_eq!(stat.tcp_orphan, 2);
+        assert_eq!(stat.tcp_tw, 187);
+        assert_eq!(stat.tcp_alloc, 58);
+        assert_eq!(stat.tcp_mem, 1240);
+        assert_eq!(stat.udp_inuse, 13);
+        assert_eq!(stat.raw_inuse, 2);
+        assert_eq!((stat.frag_inuse, stat.frag_memory), (2, 4096));
+        assert_eq!(stat.tcp_mem_limits, Some([70809, 94415, 141618]));
+
+        let (percent, pressure) = stat.tcp_mem_usage().unwrap();
+        assert!((percent - 0.8756).abs() < 0.001);
+        assert!(!pressure);
+
+        let full = SockStat {
+            tcp_mem: 94415,
+            ..stat
+        };
+        assert_eq!(
+            full.tcp_mem_usage().map(|(_, pressure)| pressure),
+            Some(true)
+        );
+
+        let unknown = sockstat("TCP: inuse 1 mem 3\n", "", "");
+        assert_eq!(unknown.tcp_mem_limits, None);
+        assert_eq!(unknown.tcp_mem_usage(), None);
+    }
+
+    #[test]
+    fn tcp_table_keeps_timer_columns() {
+        let sockets = parse_tcp_table(include_str!("../tests/fixtures/proc_net_tcp")).unwrap();
+
+        assert_eq!(sockets.len(), 4);
+        let listener = &sockets[0];
+        assert_eq!(listener.state_name(), "LISTEN");
+        assert_eq!(listener.timer.name(), "off");
+
+        let keepalive = &sockets[1].timer;
+        assert_eq!(keepalive.name(), "keepalive");
+        assert_eq!(keepalive.expires_ms, 0x148d * 10);
+
+        let retransmitting = &sockets[2];
+        assert_eq!(retransmitting.remote_ip.to_string(), "192.0.2.1");
+        assert_eq!(retransmitting.remote_port, 443);
+        assert_eq!(retransmitting.tx_queue, 0xf3c);
+        assert_eq!(retransmitting.timer.name(), "on");
+        assert_eq!(retransmitting.timer.expires_ms, 500);
+        assert_eq!(retransmitting.timer.retransmits, 3);
+        assert_eq!(retransmitting.timer.probes, 0);
+        assert_eq!(retransmitting.uid, 1000);
+
+        let time_wait = &sockets[3];
+        assert_eq!(time_wait.state_name(), "TIME_WAIT");
+        assert_eq!(time_wait.timer.name(), "timewait");
+        assert_eq!(time_wait.timer.expires_ms, 30000);
+        assert_eq!(time_wait.inode, 0);
+    }
+
+    #[test]
+    fn flow_deltas_are_per_socket() {
+        let mut sockets = parse_tcp_table(include_str!("../tests/fixtures/proc_net_tcp")).unwrap();
+        let with_counters = |s: &mut TcpStats, received: u64, acked: u64| {
+            s.tcp_info = Some(TcpInfo {
+                bytes_received: received,
+                bytes_acked: acked,
+                ..TcpInfo::default()
+            });
+        };
+        // A second socket on the same tuple, e.g. behind SO_REUSEPORT, with its own counters.
+        let mut twin = sockets[1].clone();
+        twin.inode += 1;
+        sockets.push(twin);
+        with_counters(&mut sockets[1], 1000, 500);
+        with_counters(&mut sockets[4], 0, 0);
+
+        let mut samples = HashMap::new();
+        assert!(flow_deltas(&mut samples, 1.0, &sockets).is_empty());
+        assert_eq!(samples.len(), 2);
+
+        with_counters(&mut sockets[1], 3000, 1500);
+        with_counters(&mut sockets[4], 400, 0);
+        let deltas = flow_deltas(&mut samples, 3.0, &sockets);
+        assert_eq!(
+            deltas[&sockets[1].socket_key()],
+            FlowDelta {
+                rx_bytes: 2000,
+                tx_bytes: 1000,
+                rx_rate: 1000.0,
+                tx_rate: 500.0,
+            }
+        );
+        assert_eq!(deltas[&sockets[4].socket_key()].rx_rate, 200.0);
+
+        // A socket that went away drops its sample; one without tcp_info never has one.
+        sockets.remove(4);
+        flow_deltas(&mut samples, 4.0, &sockets);
+        assert_eq!(samples.len(), 1);
+    }
+
+    #[test]
+    fn process_traffic_sums_sockets_per_pid() {
+        // Listener, two established connections of one process, and an ownerless TIME_WAIT.
+        let sockets = parse_tcp_table(include_str!("../tests/fixtures/proc_net_tcp")).unwrap();
+        let process = |pid: u32, comm: &str| ProcessInfo {
+            pid,
+            comm: comm.to_string(),
+            cmdline: String::new(),
+        };
+        let processes: HashMap<u64, ProcessInfo> = [
+            (sockets[0].inode, process(10, "nginx")),
+            (sockets[1].inode, process(20, "curl")),
+            (sockets[2].inode, process(20, "curl")),
+        ]
+        .into();
+        let delta = |rx: u64, tx: u64| FlowDelta {
+            rx_bytes: rx,
+            tx_bytes: tx,
+            rx_rate: rx as f64,
+            tx_rate: tx as f64,
+        };
+        let deltas: HashMap<SocketKey, FlowDelta> = [
+            (sockets[1].socket_key(), delta(100, 10)),
+            (sockets[2].socket_key(), delta(300, 30)),
+        ]
+        .into();
+
+        let mut traffic = HashMap::new();
+        account_process_traffic(&mut traffic, &sockets, &deltas, &processes);
+        account_process_traffic(&mut traffic, &sockets, &deltas, &processes);
+
+        assert_eq!(traffic.len(), 1);
+        let curl = &traffic[&20];
+        assert_eq!(curl.comm, "curl");
+        assert_eq!(curl.connections, 2);
+        assert_eq!((curl.rx_rate, curl.tx_rate), (400.0, 40.0));
+        assert_eq!((curl.rx_bytes, curl.tx_bytes), (800, 80));
+
+        // Rates are per tick, totals are kept.
+        account_process_traffic(&mut traffic, &sockets, &HashMap::new(), &processes);
+        assert_eq!(traffic[&20].rx_rate, 0.0);
+        assert_eq!(traffic[&20].rx_bytes, 800);
+    }
+
+    #[test]
+    fn socket_diff_reports_lifecycle_events() {
+        let sockets = parse_tcp_table(include_str!("../tests/fixtures/proc_net_tcp")).unwrap();
+        let states = |sockets: &[TcpStats]| -> HashMap<ConnectionKey, &'static str> {
+            sockets
+                .iter()
+                .map(|s| (s.connection_key(), s.state_name()))
+                .collect()
+        };
+        let prev = states(&sockets[..3]);
+
+        // The retransmitting connection starts closing, the keepalive one disappears and the
+        // TIME_WAIT one shows up.
+        let mut next = vec![sockets[0].clone(), sockets[2].clone(), sockets[3].clone()];
+        next[1].state = 0x04;
+        let events = diff_sockets(&prev, &states(&next));
+
+        assert_eq!(events.len(), 3);
+        assert!(events.contains(&SocketEvent::StateChanged(
+            sockets[2].connection_key(),
+            "ESTABLISHED",
+            "FIN_WAIT1"
+        )));
+        assert!(events.contains(&SocketEvent::Opened(sockets[3].connection_key())));
+        assert!(events.contains(&SocketEvent::Closed(sockets[1].connection_key())));
+        assert!(diff_sockets(&prev, &prev).is_empty());
+    }
+
+    #[test]
+    fn route_v4_parses_main_table() {
+        let routes = parse_route_v4(include_str!("../tests/fixtures/proc_net_route"));
+
+        assert_eq!(routes.len(), 5);
+        assert_eq!(
+            routes[0],
+            Route {
+                destination: "0.0.0.0".parse().unwrap(),
+                prefix_len: 0,
+                gateway: Some("192.0.2.1".parse().unwrap()),
+                interface: "eth0".to_string(),
+                metric: 100,
+                flags: 0x3,
+            }
+        );
+        assert_eq!(routes[1].prefix_len, 1);
+        assert_eq!(
+            routes[3].destination,
+            "172.17.0.0".parse::<IpAddr>().unwrap()
+        );
+        assert_eq!(routes[3].prefix_len, 16);
+        assert_eq!(route_flags(routes[4].flags), "UGH");
+    }
+
+    #[test]
+    fn route_v6_parses_main_and_local_tables() {
+        let routes = parse_route_v6(include_str!("../tests/fixtures/proc_net_ipv6_route"));
+
+        // The trailing ::/0 reject route on lo isn't marked up.
+        assert_eq!(routes.len(), 6);
+        assert_eq!(routes[0].destination, "fd00::".parse::<IpAddr>().unwrap());
+        assert_eq!(routes[0].prefix_len, 64);
+        assert_eq!(routes[0].metric, 256);
+        assert_eq!(routes[2].gateway, Some("fd00::1".parse().unwrap()));
+        assert_eq!(route_flags(routes[3].flags), "UL");
+        assert_eq!(routes[5].destination, "ff00::".parse::<IpAddr>().unwrap());
+        assert_eq!(routes[5].prefix_len, 8);
+    }
+
+    #[test]
+    fn route_lookup_prefers_longest_prefix() {
+        let mut routes = parse_route_v4(include_str!("../tests/fixtures/proc_net_route"));
+        routes.extend(parse_route_v6(include_str!(
+            "../tests/fixtures/proc_net_ipv6_route"
+        )));
+        let egress =
+            |ip: &str| route_lookup(&routes, &ip.parse().unwrap()).map(|r| r.interface.as_str());
+
+        assert_eq!(egress("10.10.10.10"), Some("eth0"));
+        assert_eq!(egress("8.8.8.8"), Some("wg0"));
+        assert_eq!(egress("200.1.1.1"), Some("eth0"));
+        assert_eq!(egress("172.17.0.3"), Some("docker0"));
+        assert_eq!(egress("::ffff:172.17.0.3"), Some("docker0"));
+        assert_eq!(egress("fe80::1"), Some("docker0"));
+        assert_eq!(egress("2001:db8::1"), Some("eth0"));
+        assert_eq!(egress("fd00::2"), Some("eth0"));
+    }
+
+    #[test]
+    fn bonding_active_backup() {
+        let bond = parse_bonding(include_str!(
+            "../tests/fixtures/proc_net_bonding_active_backup"
+        ));
+
+        assert_eq!(bond.mode, "fault-tolerance (active-backup)");
+        assert_eq!(bond.mii_status, "up");
+        assert_eq!(bond.active_slave.as_deref(), Some("eth1"));
+        assert_eq!(
+            bond.slaves,
+            vec![
+                BondSlave {
+                    name: "eth0".to_string(),
+                    mii_status: "down".to_string(),
+                    speed: None,
+                    link_failures: 3,
+                },
+                BondSlave {
+                    name: "eth1".to_string(),
+                    mii_status: "up".to_string(),
+                    speed: Some("1000 Mbps".to_string()),
+                    link_failures: 0,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn bonding_8023ad_ignores_lacp_details() {
+        let bond = parse_bonding(include_str!("../tests/fixtures/proc_net_bonding_8023ad"));
+
+        assert_eq!(bond.mode, "IEEE 802.3ad Dynamic link aggregation");
+        assert_eq!(bond.active_slave, None);
+        assert_eq!(bond.slaves.len(), 2);
+        assert_eq!(bond.slaves[0].name, "enp1s0f0");
+        assert_eq!(bond.slaves[0].mii_status, "up");
+        assert_eq!(bond.slaves[0].speed.as_deref(), Some("10000 Mbps"));
+        assert_eq!(bond.slaves[0].link_failures, 1);
+        assert_eq!(bond.slaves[1].name, "enp1s0f1");
+    }
+
+    #[test]
+    fn vlan_config_maps_to_parent() {
+        let vlans = parse_vlan_config(include_str!("../tests/fixtures/proc_net_vlan_config"));
+        assert_eq!(
+            vlans,
+            vec![
+                ("eth0.100".to_string(), "eth0".to_string()),
+                ("bond0.20".to_string(), "bond0".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn cgroup_matches_each_runtime() {
+        let id = "4f1c2a9e7b3d5c6a8e9f0a1b2c3d4e5f6a7b8c9d0e1f2a3b4c5d6e7f8a9b0c1d";
+        let cases = [
+            (format!("0::/system.slice/docker-{}.scope", id), "docker"),
+            (format!("12:memory:/docker/{}", id), "docker"),
+            (format!("0::/machine.slice/libpod-{}.scope/container", id), "podman"),
+            (
+                format!("0::/kubepods.slice/kubepods-besteffort.slice/kubepods-besteffort-pod1.slice/cri-containerd-{}.scope", id),
+                "containerd",
+            ),
+            (format!("0::/kubepods/burstable/pod7a2b/{}", id), "containerd"),
+        ];
+        for (cgroup, runtime) in cases {
+            let container = parse_cgroup_container(&cgroup).unwrap();
+            assert_eq!(container.runtime, runtime, "{}", cgroup);
+            assert_eq!(container.id, id);
+            assert_eq!(container.label(), &id[..12]);
+        }
+    }
+
+    #[test]
+    fn cgroup_matches_nspawn_machines() {
+        let scope = parse_cgroup_container("0::/machine.slice/machine-my\\x2dbox.scope/payload");
+        let service =
+            parse_cgroup_container("0::/machine.slice/systemd-nspawn@web.service/payload");
+        assert_eq!(
+            scope.map(|c| (c.runtime, c.label())),
+            Some(("nspawn", "my-box".to_string()))
+        );
+        assert_eq!(
+            service.map(|c| (c.runtime, c.label())),
+            Some(("nspawn", "web".to_string()))
+        );
+
+        let utf8 =
+            parse_cgroup_container("0::/machine.slice/machine-caf\\xc3\\xa9\\x2d1.scope/payload");
+        assert_eq!(utf8.map(|c| c.label()), Some("café-1".to_string()));
+    }
+
+    #[test]
+    fn cgroup_ignores_hosts_and_conmon() {
+        assert_eq!(parse_cgroup_container("0::/init.scope"), None);
+        assert_eq!(
+            parse_cgroup_container("0::/user.slice/user-1000.slice/session-2.scope"),
+            None
+        );
+        assert_eq!(
+            parse_cgroup_container("0::/machine.slice/libpod-conmon-4f1c.scope"),
+            None
+        );
+    }
+
+    #[test]
+    fn wireless_skips_truncated_rows() {
+        let stats = parse_wireless(include_str!(
+            "../tests/fixtures/proc_net_wireless_truncated"
+        ));
+
+        assert_eq!(stats.len(), 1);
+        assert_eq!(stats[0].name, "wlan1");
+        assert_eq!(stats[0].level, -80.0);
+        assert_eq!(stats[0].noise, Some(-92.0));
+    }
+}
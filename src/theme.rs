@@ -50,6 +50,7 @@ pub struct InfoAreaColor {
     pub heading: Color,
     pub key: Color,
     pub val: Color,
+    pub changed: Color,
 }
 
 pub struct RxAreaColor {
@@ -63,6 +64,7 @@ pub struct SparkLineColor {
     pub tx_border_color: Color,
     pub rx_sparkline: Color,
     pub tx_sparkline: Color,
+    pub discontinuity: Color,
 }
 
 pub struct TxAreaColor {
@@ -133,7 +135,9 @@ pub struct Theme {
     pub tx_popup_color: TxPopupColor,
 }
 
-pub static THEMES: [(&str, fn() -> Theme); 6] = [
+pub type ThemeEntry = (&'static str, fn() -> Theme);
+
+pub static THEMES: [ThemeEntry; 6] = [
     ("Default", Theme::default),
     ("Gruvbox", gruvbox),
     ("Ayu", ayu),
@@ -195,6 +199,7 @@ impl Default for Theme {
                 heading: Color::Yellow,
                 key: common.heading,
                 val: common.data,
+                changed: Color::LightYellow,
             },
 
             rx_area_color: RxAreaColor {
@@ -238,6 +243,7 @@ impl Default for Theme {
                 tx_border_color: Color::Blue,
                 rx_sparkline: Color::Green,
                 tx_sparkline: Color::Blue,
+                discontinuity: Color::Red,
             },
 
             help_popup_color: HelpPopupColor {
@@ -315,6 +321,7 @@ fn nord() -> Theme {
             heading: Color::Rgb(136, 192, 208),
             key: common.heading,
             val: common.data,
+            changed: Color::Rgb(235, 203, 139),
         },
 
         rx_area_color: RxAreaColor {
@@ -358,6 +365,7 @@ fn nord() -> Theme {
             tx_border_color: Color::Rgb(94, 129, 172),
             rx_sparkline: Color::Rgb(143, 188, 187),
             tx_sparkline: Color::Rgb(94, 129, 172),
+            discontinuity: Color::Rgb(191, 97, 106),
         },
         help_popup_color: HelpPopupColor {
             border: Color::Rgb(136, 192, 208),
@@ -433,6 +441,7 @@ fn gruvbox() -> Theme {
             heading: Color::Rgb(250, 189, 47),
             key: common.heading,
             val: common.data,
+            changed: Color::Rgb(254, 128, 25),
         },
 
         rx_area_color: RxAreaColor {
@@ -476,6 +485,7 @@ fn gruvbox() -> Theme {
             tx_border_color: Color::Rgb(131, 165, 152),
             rx_sparkline: Color::Rgb(104, 157, 106),
             tx_sparkline: Color::Rgb(69, 133, 136),
+            discontinuity: Color::Rgb(204, 36, 29),
         },
         help_popup_color: HelpPopupColor {
             border: Color::Rgb(250, 189, 47),
@@ -550,6 +560,7 @@ fn solarized_dark() -> Theme {
             heading: Color::Rgb(181, 137, 0),
             key: common.heading,
             val: common.data,
+            changed: Color::Rgb(203, 75, 22),
         },
 
         rx_area_color: RxAreaColor {
@@ -593,6 +604,7 @@ fn solarized_dark() -> Theme {
             tx_border_color: Color::Rgb(133, 153, 0),
             rx_sparkline: Color::Rgb(42, 161, 152),
             tx_sparkline: Color::Rgb(133, 153, 0),
+            discontinuity: Color::Rgb(220, 50, 47),
         },
 
         help_popup_color: HelpPopupColor {
@@ -669,6 +681,7 @@ fn catppuccin_mocha() -> Theme {
             heading: Color::Rgb(249, 226, 175),
             key: common.heading,
             val: common.data,
+            changed: Color::Rgb(249, 226, 175),
         },
 
         rx_area_color: RxAreaColor {
@@ -712,6 +725,7 @@ fn catppuccin_mocha() -> Theme {
             tx_border_color: Color::Rgb(203, 166, 247),
             rx_sparkline: Color::Rgb(166, 227, 161),
             tx_sparkline: Color::Rgb(203, 166, 247),
+            discontinuity: Color::Rgb(243, 139, 168),
         },
 
         help_popup_color: HelpPopupColor {
@@ -790,6 +804,7 @@ fn ayu() -> Theme {
             heading: Color::Rgb(255, 180, 84),
             key: common.heading,
             val: common.data,
+            changed: Color::Rgb(255, 180, 84),
         },
 
         rx_area_color: RxAreaColor {
@@ -832,6 +847,7 @@ fn ayu() -> Theme {
             tx_border_color: Color::Blue,
             rx_sparkline: Color::Green,
             tx_sparkline: Color::Blue,
+            discontinuity: Color::Red,
         },
 
         help_popup_color: HelpPopupColor {
@@ -1,7 +1,8 @@
 use std::{
     collections::{HashMap, HashSet, VecDeque},
-    net::{IpAddr, Ipv4Addr},
+    net::IpAddr,
     sync::Arc,
+    time::Instant,
     vec,
 };
 
@@ -11,9 +12,9 @@ use ratatui::{
     symbols::{self},
     text::{Line, Span},
     widgets::{
-        Block, BorderType, Cell, Clear, HighlightSpacing, List, ListItem, ListState, Paragraph,
-        RenderDirection, Row, Scrollbar, ScrollbarOrientation, ScrollbarState, Sparkline, Table,
-        TableState,
+        Block, BorderType, Borders, Cell, Clear, HighlightSpacing, List, ListItem, ListState,
+        Paragraph, RenderDirection, Row, Scrollbar, ScrollbarOrientation, ScrollbarState,
+        Sparkline, Table, TableState,
     },
     Frame,
 };
@@ -160,6 +161,200 @@ pub fn compute_totals(data: &[NetworkStats]) -> NetTotals {
     totals
 }
 
+// One line per RX or TX counter the backend provided, with its rate while it is moving.
+fn counter_lines<'a>(
+    app: &App,
+    counters: Vec<(&'static str, Option<u64>)>,
+    rates: &[f64],
+    key: Color,
+    val: Color,
+) -> Vec<Line<'a>> {
+    // Breakdowns (the indented rows) only show up once they count something, so the column
+    // fits the detail area on short terminals.
+    counters
+        .into_iter()
+        .enumerate()
+        .filter_map(|(i, (name, value))| {
+            let value = value.filter(|v| *v > 0 || !name.starts_with(' '))?;
+            let mut spans = vec![
+                Span::styled(format!(" {:<12}: ", name), Style::default().fg(key)),
+                Span::styled(value.to_string(), Style::default().fg(val)),
+            ];
+            if let Some(rate) = rates.get(i).filter(|r| **r > 0.0) {
+                spans.push(Span::styled(
+                    format!(" +{:.1}/s", rate),
+                    Style::default()
+                        .fg(app.current_theme.info_area_color.changed)
+                        .add_modifier(Modifier::BOLD),
+                ));
+            }
+            Some(Line::from(spans))
+        })
+        .collect()
+}
+
+fn render_wireless_section(frame: &mut Frame, area: Rect, app: &App, wireless: &WirelessStats) {
+    let theme = &app.current_theme.info_area_color;
+    let block = Block::bordered()
+        .border_type(BorderType::Plain)
+        .title(" WIRELESS ")
+        .title_style(Style::new().bold())
+        .border_style(Style::default().fg(theme.heading));
+    let inner = block.inner(area);
+    frame.render_widget(block, area);
+
+    let rows = Layout::vertical([Constraint::Length(7), Constraint::Fill(1)]).split(inner);
+
+    let dbm = |v: f64| {
+        if v < 0.0 {
+            format!("{:.0} dBm", v)
+        } else {
+            format!("{:.0}", v)
+        }
+    };
+    let discarded = wireless.discarded_nwid
+        + wireless.discarded_crypt
+        + wireless.discarded_frag
+        + wireless.discarded_retry
+        + wireless.discarded_misc;
+
+    let lines: Vec<Line> = [
+        (" Quality     : ", format!("{:.0}", wireless.link)),
+        (" Signal      : ", dbm(wireless.level)),
+        (
+            " Noise       : ",
+            wireless.noise.map(dbm).unwrap_or("-".to_string()),
+        ),
+        (" Discarded   : ", discarded.to_string()),
+        (
+            "   retry/misc: ",
+            format!("{}/{}", wireless.discarded_retry, wireless.discarded_misc),
+        ),
+        (" Missed bcn  : ", wireless.missed_beacon.to_string()),
+    ]
+    .into_iter()
+    .map(|(key, val)| {
+        Line::from(vec![
+            Span::styled(key, Style::default().fg(theme.key)),
+            Span::styled(val, Style::default().fg(theme.val)),
+        ])
+    })
+    .collect();
+    frame.render_widget(Paragraph::new(lines), rows[0]);
+
+    // dBm readings are negative; shift them so -100 dBm sits at the bottom of the graph.
+    let signal: Vec<u64> = app
+        .signal_history
+        .get(&wireless.name)
+        .map(|history| {
+            history
+                .iter()
+                .map(|v| if *v < 0.0 { (v + 100.0).max(0.0) } else { *v } as u64)
+                .collect()
+        })
+        .unwrap_or_default();
+    let visible = signal.len().saturating_sub(rows[1].width as usize);
+    let spark = Sparkline::default()
+        .block(
+            Block::default()
+                .borders(Borders::TOP)
+                .title(" Signal ")
+                .border_style(Style::default().fg(theme.heading)),
+        )
+        .data(&signal[visible..])
+        .max(signal.iter().copied().max().unwrap_or(0).max(100))
+        .style(Style::default().fg(app.current_theme.sparkline_area_color.rx_sparkline))
+        .direction(RenderDirection::LeftToRight);
+    frame.render_widget(spark, rows[1]);
+}
+
+// Members of a bridge, bond or VLAN parent with their rates; bonds add MII state per slave.
+fn render_members_section(frame: &mut Frame, area: Rect, app: &App, parent: &NetworkStats) {
+    let theme = &app.current_theme.info_area_color;
+    let bond = app.bonds.get(&parent.name);
+    let title = match (bond, parent.link.as_ref().and_then(|l| l.kind.as_deref())) {
+        (Some(_), _) => " MEMBERS (bond) ".to_string(),
+        (None, Some(kind)) => format!(" MEMBERS ({}) ", kind),
+        (None, None) => " MEMBERS ".to_string(),
+    };
+    let block = Block::bordered()
+        .border_type(BorderType::Plain)
+        .title(title)
+        .title_style(Style::new().bold())
+        .border_style(Style::default().fg(theme.heading));
+
+    let key = |k: String| Span::styled(k, Style::default().fg(theme.key));
+    let val = |v: String| Span::styled(v, Style::default().fg(theme.val));
+    let rate = |data: &HashMap<String, Vec<Sample>>, name: &str| {
+        let (value, unit) = human_speed(data.get(name).and_then(|s| last_rate(s)).unwrap_or(0.0));
+        format!("{:.1} {}", value, unit)
+    };
+
+    let mut lines = Vec::new();
+    if let Some(bond) = bond {
+        // "fault-tolerance (active-backup)" reads better as just the mode name.
+        let mode = bond
+            .mode
+            .split_once('(')
+            .map(|(_, m)| m.trim_end_matches(')'))
+            .unwrap_or(&bond.mode);
+        lines.push(Line::from(vec![
+            key(" Mode        : ".into()),
+            val(mode.to_string()),
+        ]));
+        lines.push(Line::from(vec![
+            key(" Active      : ".into()),
+            val(bond.active_slave.clone().unwrap_or("-".to_string())),
+        ]));
+        lines.push(Line::from(vec![
+            key(" MII         : ".into()),
+            val(bond.mii_status.clone()),
+        ]));
+    }
+    let (rx, tx) = app.children_rate(&parent.name);
+    let ((rx, rx_unit), (tx, tx_unit)) = (human_speed(rx), human_speed(tx));
+    lines.push(Line::from(vec![
+        key(" Σ RX / TX   : ".into()),
+        val(format!("{:.1} {} / {:.1} {}", rx, rx_unit, tx, tx_unit)),
+    ]));
+    lines.push(Line::from(""));
+
+    for child in app.children_of(&parent.name) {
+        let slave = bond.and_then(|b| b.slaves.iter().find(|s| s.name == child.name));
+        // Bond slaves are coloured by MII state, which is what failover goes by.
+        let state = match slave {
+            Some(slave) if slave.mii_status == "up" => 6,
+            Some(_) => 2,
+            None => child.link.as_ref().map_or(0, |l| l.operstate),
+        };
+        let mut spans = vec![
+            Span::styled(" ● ", Style::default().fg(operstate_color(app, state))),
+            key(format!("{:<12}", child.name)),
+            val(format!(
+                " ↓{} ↑{}",
+                rate(&app.rx_data, &child.name),
+                rate(&app.tx_data, &child.name)
+            )),
+        ];
+        if let Some(slave) = slave {
+            if bond.and_then(|b| b.active_slave.as_ref()) == Some(&slave.name) {
+                spans.push(Span::styled(
+                    " active",
+                    Style::default()
+                        .fg(operstate_color(app, 6))
+                        .add_modifier(Modifier::BOLD),
+                ));
+            }
+            if slave.link_failures > 0 {
+                spans.push(key(format!(" {} fail", slave.link_failures)));
+            }
+        }
+        lines.push(Line::from(spans));
+    }
+
+    frame.render_widget(Paragraph::new(lines).block(block), area);
+}
+
 fn render_overview_graph(frame: &mut Frame, area: Rect, app: &App) {
     let rows =
         Layout::vertical([Constraint::Percentage(50), Constraint::Percentage(50)]).split(area);
@@ -176,9 +371,12 @@ fn render_overview_graph(frame: &mut Frame, area: Rect, app: &App) {
         )
         .data(rx_data)
         .style(Style::default().fg(app.current_theme.sparkline_area_color.rx_border_color))
-        .max(rx_data.iter().copied().max().unwrap_or(0))
+        .max(rx_data.iter().flatten().copied().max().unwrap_or(0))
         .direction(RenderDirection::LeftToRight)
-        .absent_value_symbol(symbols::line::HORIZONTAL);
+        .absent_value_symbol(symbols::line::DOUBLE_VERTICAL)
+        .absent_value_style(
+            Style::default().fg(app.current_theme.sparkline_area_color.discontinuity),
+        );
 
     let tx_spark = Sparkline::default()
         .block(
@@ -189,9 +387,12 @@ fn render_overview_graph(frame: &mut Frame, area: Rect, app: &App) {
         )
         .data(tx_data)
         .style(Style::default().fg(app.current_theme.sparkline_area_color.tx_border_color))
-        .max(tx_data.iter().copied().max().unwrap_or(0))
+        .max(tx_data.iter().flatten().copied().max().unwrap_or(0))
         .direction(RenderDirection::LeftToRight)
-        .absent_value_symbol(symbols::line::HORIZONTAL);
+        .absent_value_symbol(symbols::line::DOUBLE_VERTICAL)
+        .absent_value_style(
+            Style::default().fg(app.current_theme.sparkline_area_color.discontinuity),
+        );
 
     frame.render_widget(rx_spark, rows[0]);
     frame.render_widget(tx_spark, rows[1]);
@@ -281,58 +482,197 @@ pub fn draw_speed_edit_popup(frame: &mut Frame, app: &App) {
     frame.render_widget(popup, popup_area);
 }
 
-pub fn tcp_matches_filter(
-    conn: &TcpStats,
+// Optional columns of the sockets table, derived from the view and the socket backend.
+#[derive(Clone, Copy)]
+pub struct SocketColumns {
+    pub proto: bool,
+    pub drops: bool,
+    pub tcp_info: bool,
+}
+
+impl SocketColumns {
+    pub fn new(view: SocketView, backend: Backend) -> Self {
+        Self {
+            proto: view == SocketView::All,
+            drops: view != SocketView::Tcp,
+            tcp_info: view != SocketView::Udp && backend == Backend::Netlink,
+        }
+    }
+}
+
+// Sockets listed by the bottom table for the current view, TCP first.
+pub fn collect_sockets<'a>(
+    view: SocketView,
+    tcp_data: &'a [TcpStats],
+    udp_data: &'a [UdpStats],
+) -> Vec<&'a dyn Socket> {
+    let tcp = tcp_data.iter().map(|c| c as &dyn Socket);
+    let udp = udp_data.iter().map(|c| c as &dyn Socket);
+    match view {
+        SocketView::Tcp => tcp.collect(),
+        SocketView::Udp => udp.collect(),
+        SocketView::All => tcp.chain(udp).collect(),
+    }
+}
+
+// Neighbour filter: IP, MAC, device, flags, state and hostname. Entries whose MAC just
+// changed also match "changed" and their previous MAC.
+pub fn neighbor_matches_filter(
+    neighbor: &Neighbor,
+    mac_changes: &HashMap<(IpAddr, String), (String, Instant)>,
+    filter: &str,
+    hostname_cache: &HashMap<IpAddr, String>,
+) -> bool {
+    if filter.is_empty() {
+        return true;
+    }
+    let filter = filter.to_lowercase();
+    let changed = mac_changes.get(&(neighbor.ip, neighbor.device.clone()));
+
+    [
+        Some(neighbor.ip.to_string()),
+        neighbor.mac.clone(),
+        Some(neighbor.device.clone()),
+        Some(neighbor.flags.clone()),
+        Some(neighbor.state.to_string()),
+        hostname_cache.get(&neighbor.ip).cloned(),
+        changed.map(|(old, _)| format!("changed {}", old)),
+    ]
+    .into_iter()
+    .flatten()
+    .any(|field| field.to_lowercase().contains(&filter))
+}
+
+// Conntrack filter: protocol, state, either tuple, mark, and the words snat, dnat, assured
+// and unreplied.
+pub fn conntrack_matches_filter(entry: &ConntrackEntry, filter: &str) -> bool {
+    if filter.is_empty() {
+        return true;
+    }
+    let filter = filter.to_lowercase();
+
+    [
+        Some(entry.protocol.clone()),
+        entry.state.clone(),
+        Some(conntrack_endpoint(
+            &entry.original.src,
+            entry.original.sport,
+        )),
+        Some(conntrack_endpoint(
+            &entry.original.dst,
+            entry.original.dport,
+        )),
+        Some(conntrack_endpoint(&entry.reply.src, entry.reply.sport)),
+        Some(conntrack_endpoint(&entry.reply.dst, entry.reply.dport)),
+        Some(format!("mark={}", entry.mark)),
+        entry.snat().then(|| "snat".to_string()),
+        entry.dnat().then(|| "dnat".to_string()),
+        entry.assured.then(|| "assured".to_string()),
+        entry.unreplied.then(|| "unreplied".to_string()),
+    ]
+    .into_iter()
+    .flatten()
+    .any(|field| field.to_lowercase().contains(&filter))
+}
+
+fn conntrack_endpoint(ip: &IpAddr, port: Option<u16>) -> String {
+    match port {
+        Some(port) => format_ip_port(ip, port),
+        None => ip.to_string(),
+    }
+}
+
+pub fn socket_matches_filter(
+    conn: &dyn Socket,
     filter: &str,
-    hostname_cache: &HashMap<[u8; 4], String>,
+    hostname_cache: &HashMap<IpAddr, String>,
+    process_cache: &HashMap<u64, ProcessInfo>,
 ) -> bool {
     if filter.is_empty() {
         return true;
     }
 
+    // `pid=N` matches only the sockets of that process, as set from the Processes view.
+    if let Some(pid) = filter
+        .strip_prefix("pid=")
+        .and_then(|p| p.parse::<u32>().ok())
+    {
+        return process_cache
+            .get(&conn.inode())
+            .is_some_and(|p| p.pid == pid);
+    }
+
     let filter_lower = filter.to_lowercase();
 
-    let local_addr = format!("{}:{}", format_ip(&conn.local_ip), conn.local_port);
+    let local_addr = format_ip_port(&conn.local_ip(), conn.local_port());
     if local_addr.to_lowercase().contains(&filter_lower) {
         return true;
     }
 
-    let remote_addr = format!("{}:{}", format_ip(&conn.remote_ip), conn.remote_port);
+    let remote_addr = format_ip_port(&conn.remote_ip(), conn.remote_port());
     if remote_addr.to_lowercase().contains(&filter_lower) {
         return true;
     }
 
-    if let Some(hostname) = hostname_cache.get(&conn.remote_ip) {
+    if let Some(hostname) = hostname_cache.get(&conn.remote_ip()) {
         if hostname.to_lowercase().contains(&filter_lower) {
             return true;
         }
     }
 
-    let state = tcp_state_name(conn.state);
+    let state = conn.state_name();
     if state.to_lowercase().contains(&filter_lower) {
         return true;
     }
 
-    if format!("{}", conn.uid).contains(&filter_lower) {
+    if conn
+        .protocol()
+        .name()
+        .to_lowercase()
+        .contains(&filter_lower)
+    {
         return true;
     }
 
-    if format!("{}", conn.inode).contains(&filter_lower) {
+    if let Some(process) = process_cache.get(&conn.inode()) {
+        if process.comm.to_lowercase().contains(&filter_lower)
+            || process.cmdline.to_lowercase().contains(&filter_lower)
+            || process.pid.to_string().contains(&filter_lower)
+        {
+            return true;
+        }
+    } else if process_label(conn, process_cache).contains(&filter_lower) {
+        return true;
+    }
+
+    if format!("{}", conn.uid()).contains(&filter_lower) {
+        return true;
+    }
+
+    if format!("{}", conn.inode()).contains(&filter_lower) {
         return true;
     }
 
     false
 }
 
+// `-` for sockets no process holds (TIME_WAIT), `unknown` when the owner's fds aren't readable.
+pub fn process_label(conn: &dyn Socket, process_cache: &HashMap<u64, ProcessInfo>) -> String {
+    if conn.inode() == 0 {
+        return "-".to_string();
+    }
+    process_cache
+        .get(&conn.inode())
+        .map(|p| p.label())
+        .unwrap_or("unknown".to_string())
+}
+
 pub fn draw_interface_mode(
     app: &mut App,
     frame: &mut Frame,
-    data: &Vec<NetworkStats>,
-    tcp_data: &Vec<TcpStats>,
-    rx_peak_speed: &mut HashMap<String, f64>,
-    tx_peak_speed: &mut HashMap<String, f64>,
-    rx_avg_speed: &mut HashMap<String, f64>,
-    tx_avg_speed: &mut HashMap<String, f64>,
+    data: &[NetworkStats],
+    tcp_data: &[TcpStats],
+    udp_data: &[UdpStats],
 ) {
     let interface_border = if app.focus == Focus::Interfaces {
         app.current_theme
@@ -342,15 +682,9 @@ pub fn draw_interface_mode(
         app.current_theme.interface_area_color.border
     };
 
-    let tcp_border = if app.focus == Focus::TcpTable {
-        app.current_theme.tcpconn_area_color.filter
-    } else {
-        app.current_theme.tcpconn_area_color.border
-    };
-
     let byte_unit = app.byte_unit.clone();
     let area = frame.area();
-    let uptime = parse_uptime().unwrap_or(String::new());
+    let uptime = parse_uptime().unwrap_or_default();
     let tick_millis = app.tick_rate.as_millis();
     let tick_display = if tick_millis >= 1000 {
         format!("{:.1}s", (tick_millis as f64) / 1000.0)
@@ -364,7 +698,7 @@ pub fn draw_interface_mode(
     let tcp_area = chunks[1];
 
     let main_split =
-        Layout::horizontal([Constraint::Length(25), Constraint::Fill(1)]).split(main_part);
+        Layout::horizontal([Constraint::Length(27), Constraint::Fill(1)]).split(main_part);
 
     let list_area = main_split[0];
     let detail_area = main_split[1];
@@ -372,19 +706,17 @@ pub fn draw_interface_mode(
     let rx_data_strings = get_network_receive_data(app, data);
     let tx_data_lines = get_network_transmit_data(app, data);
 
-    let mut interface_names: VecDeque<String> = data.iter().map(|i| i.name.clone()).collect();
+    let interface_names = app.interface_names();
 
     match &app.mode {
         Mode::SelectingInterface { filter, index } => {
-            interface_names.push_front(String::from("all"));
-
             let filtered: VecDeque<(usize, &String)> = interface_names
                 .iter()
                 .enumerate()
-                .filter(|(_, name)| name.contains(filter))
+                .filter(|(_, name)| interface_matches(&app.veth_peers, name, filter))
                 .collect();
 
-            let items = interface_vec_items(Some(&filtered), &app, &interface_names);
+            let items = interface_vec_items(Some(&filtered), app, &interface_names, data);
 
             let mut state = ListState::default();
             if !filtered.is_empty() {
@@ -428,11 +760,9 @@ pub fn draw_interface_mode(
             );
         }
         _ => {
-            interface_names.push_front(String::from("all"));
-
-            let items = interface_vec_items(None, &app, &mut interface_names);
+            let items = interface_vec_items(None, app, &interface_names, data);
 
-            let interface_count = interface_names.iter().count();
+            let interface_count = interface_names.len();
             let mut state = ListState::default();
             state.select(Some(app.vertical_scroll));
 
@@ -471,18 +801,22 @@ pub fn draw_interface_mode(
 
     match app.selected_interface.clone() {
         InterfaceSelected::Interface(selected_name) => {
-            if let Some(interface_data) = data.iter().find(|i| i.name == selected_name) {
-                let idx = data.iter().position(|i| i.name == selected_name).unwrap();
+            // A vanished interface keeps showing its last counters until the grace period ends.
+            let interface_data = data
+                .iter()
+                .find(|i| i.name == selected_name)
+                .or_else(|| app.gone_interfaces.get(&selected_name).map(|(s, _)| s))
+                .cloned();
+            if let Some(interface_data) = interface_data {
+                let idx = data.iter().position(|i| i.name == selected_name);
 
                 let rx_speed_str = extract_speed_from_line(
-                    &rx_data_strings
-                        .get(idx)
+                    &idx.and_then(|i| rx_data_strings.get(i))
                         .cloned()
                         .unwrap_or(Line::from("0 B/s")),
                 );
                 let tx_speed_str = extract_speed_from_line(
-                    &tx_data_lines
-                        .get(idx)
+                    &idx.and_then(|i| tx_data_lines.get(i))
                         .cloned()
                         .unwrap_or(Line::from("0 B/s")),
                 );
@@ -496,29 +830,11 @@ pub fn draw_interface_mode(
                 ])
                 .split(detail_area);
 
-                let rx_current = parse_speed(&rx_speed_str, None);
-                let tx_current = parse_speed(&tx_speed_str, None);
-                let rx_peak = rx_peak_speed.entry(selected_name.clone()).or_insert(0.0);
-                if rx_current > *rx_peak {
-                    *rx_peak = rx_current
-                };
-
-                let tx_peak = tx_peak_speed.entry(selected_name.clone()).or_insert(0.0);
-                if tx_current > *tx_peak {
-                    *tx_peak = tx_current;
-                }
-
-                let rx_avg_ref = rx_avg_speed.entry(selected_name.clone()).or_insert(0.0);
-                let tx_avg_ref = tx_avg_speed.entry(selected_name.clone()).or_insert(0.0);
-
-                if app.update_avg {
-                    app.update_avg = false;
-                    *rx_avg_ref = *rx_avg_ref * 0.95 + rx_current * 0.05;
-                    *tx_avg_ref = *tx_avg_ref * 0.95 + tx_current * 0.05;
-                }
-
-                let rx_avg = *rx_avg_ref;
-                let tx_avg = *tx_avg_ref;
+                let stat = |map: &HashMap<String, f64>| *map.get(&selected_name).unwrap_or(&0.0);
+                let rx_peak = stat(&app.rx_peak_speed);
+                let tx_peak = stat(&app.tx_peak_speed);
+                let rx_avg = stat(&app.rx_avg_speed);
+                let tx_avg = stat(&app.tx_avg_speed);
 
                 let rx_speed = app.get_rx_limit(&interface_data.name);
                 let tx_speed = app.get_tx_limit(&interface_data.name);
@@ -526,9 +842,9 @@ pub fn draw_interface_mode(
                 let rx_load = parse_speed(&rx_speed_str, Some(rx_speed));
                 let tx_load = parse_speed(&tx_speed_str, Some(tx_speed));
 
-                let rx_peak_str = format_speed_mbps(*rx_peak);
+                let rx_peak_str = format_speed_mbps(rx_peak);
                 let rx_avg_str = format_speed_mbps(rx_avg);
-                let tx_peak_str = format_speed_mbps(*tx_peak);
+                let tx_peak_str = format_speed_mbps(tx_peak);
                 let tx_avg_str = format_speed_mbps(tx_avg);
 
                 let rx_para = Paragraph::new(vec![
@@ -562,7 +878,11 @@ pub fn draw_interface_mode(
                             Span::styled(
                                 format!("{:<10}", rx_speed_str),
                                 Style::default()
-                                    .fg(app.current_theme.rxbar_area_color.current_val)
+                                    .fg(if rx_speed_str == COUNTER_RESET {
+                                        app.current_theme.sparkline_area_color.discontinuity
+                                    } else {
+                                        app.current_theme.rxbar_area_color.current_val
+                                    })
                                     .add_modifier(Modifier::BOLD),
                             ),
                             Span::raw(" │ "),
@@ -609,8 +929,8 @@ pub fn draw_interface_mode(
                                 Span::styled(
                                     format!(
                                         "{} Mbps",
-                                        app.interface_speeds
-                                            .get(&selected_name)
+                                        app.link_speed(&selected_name)
+                                            .0
                                             .map(|(rx, _)| format!("{:.0}", rx))
                                             .unwrap_or("?".to_string())
                                     ),
@@ -618,6 +938,11 @@ pub fn draw_interface_mode(
                                         .fg(app.current_theme.rxbar_area_color.link_speed_val)
                                         .add_modifier(Modifier::BOLD),
                                 ),
+                                Span::styled(
+                                    format!(" ({})", link_speed_source(app, &selected_name)),
+                                    Style::default()
+                                        .fg(app.current_theme.rxbar_area_color.link_speed_heading),
+                                ),
                                 Span::raw(" "),
                             ])
                             .right_aligned(),
@@ -659,7 +984,11 @@ pub fn draw_interface_mode(
                             Span::styled(
                                 format!("{:<10}", tx_speed_str),
                                 Style::default()
-                                    .fg(app.current_theme.txbar_area_color.current_val)
+                                    .fg(if tx_speed_str == COUNTER_RESET {
+                                        app.current_theme.sparkline_area_color.discontinuity
+                                    } else {
+                                        app.current_theme.txbar_area_color.current_val
+                                    })
                                     .add_modifier(Modifier::BOLD),
                             ),
                             Span::raw(" │ "),
@@ -709,8 +1038,8 @@ pub fn draw_interface_mode(
                                 Span::styled(
                                     format!(
                                         "{} Mbps",
-                                        app.interface_speeds
-                                            .get(&selected_name)
+                                        app.link_speed(&selected_name)
+                                            .0
                                             .map(|(_, tx)| format!("{:.0}", tx))
                                             .unwrap_or("?".to_string())
                                     ),
@@ -718,6 +1047,11 @@ pub fn draw_interface_mode(
                                         .fg(app.current_theme.txbar_area_color.link_speed_val)
                                         .add_modifier(Modifier::BOLD),
                                 ),
+                                Span::styled(
+                                    format!(" ({})", link_speed_source(app, &selected_name)),
+                                    Style::default()
+                                        .fg(app.current_theme.txbar_area_color.link_speed_heading),
+                                ),
                                 Span::raw(" "),
                             ])
                             .right_aligned(),
@@ -730,14 +1064,20 @@ pub fn draw_interface_mode(
                 let rx_bytes_str = interface_data.receive.display(app, None);
                 let tx_bytes_str = interface_data.transmit.display(app, None);
 
-                let stats_columns = Layout::horizontal([
-                    Constraint::Percentage(33),
-                    Constraint::Percentage(33),
-                    Constraint::Percentage(34),
-                ])
-                .split(detail_chunks[2]);
+                let wireless = app.wireless_stats.get(&selected_name);
+                let has_members = !app.children_of(&selected_name).is_empty();
+                let stats_columns = if wireless.is_some() || has_members {
+                    Layout::horizontal([Constraint::Ratio(1, 4); 4]).split(detail_chunks[2])
+                } else {
+                    Layout::horizontal([
+                        Constraint::Percentage(33),
+                        Constraint::Percentage(33),
+                        Constraint::Percentage(34),
+                    ])
+                    .split(detail_chunks[2])
+                };
 
-                let left_col = Paragraph::new(vec![
+                let mut info_lines = vec![
                     Line::from(vec![
                         Span::styled(
                             " Name        : ",
@@ -802,8 +1142,68 @@ pub fn draw_interface_mode(
                             Style::default().fg(app.current_theme.info_area_color.val),
                         ),
                     ]),
-                ])
-                .block(
+                ];
+
+                if let Some(link) = &interface_data.link {
+                    let name_of = |ifindex: Option<u32>| {
+                        ifindex.filter(|i| *i != 0 && *i != link.ifindex).map(|i| {
+                            data.iter()
+                                .find(|d| d.link.as_ref().is_some_and(|l| l.ifindex == i))
+                                .map(|d| d.name.clone())
+                                .unwrap_or(format!("if{}", i))
+                        })
+                    };
+                    let key = |k: &'static str| {
+                        Span::styled(
+                            k,
+                            Style::default().fg(app.current_theme.info_area_color.key),
+                        )
+                    };
+                    let val = |v: String| {
+                        Span::styled(
+                            v,
+                            Style::default().fg(app.current_theme.info_area_color.val),
+                        )
+                    };
+
+                    info_lines.push(Line::from(""));
+                    info_lines.push(Line::from(vec![
+                        key(" State       : "),
+                        Span::styled(
+                            operstate_name(link.operstate),
+                            Style::default()
+                                .fg(operstate_color(app, link.operstate))
+                                .add_modifier(Modifier::BOLD),
+                        ),
+                    ]));
+                    info_lines.push(Line::from(vec![
+                        key(" Index / MTU : "),
+                        val(format!("{} / {}", link.ifindex, link.mtu)),
+                    ]));
+                    if let Some(mac) = &link.mac {
+                        info_lines.push(Line::from(vec![key(" MAC         : "), val(mac.clone())]));
+                    }
+                    if let Some(master) = name_of(link.master) {
+                        info_lines.push(Line::from(vec![key(" Master      : "), val(master)]));
+                    }
+                    // A veth's iflink is an ifindex in the peer's namespace, not ours.
+                    if let Some(peer) = app.veth_peers.get(&interface_data.name) {
+                        if let Some(container) = &peer.container {
+                            info_lines.push(Line::from(vec![
+                                key(" Container   : "),
+                                val(format!("{} {}", container.runtime, container.label())),
+                            ]));
+                        }
+                        info_lines.push(Line::from(vec![
+                            key(" Peer        : "),
+                            val(format!("{} @ {}", peer.peer, peer.netns)),
+                        ]));
+                    } else if let Some(lower) = name_of(link.link) {
+                        info_lines.push(Line::from(vec![key(" Link        : "), val(lower)]));
+                    }
+                }
+
+                let left_col = Paragraph::new(info_lines).block(
                     Block::bordered()
                         .border_type(BorderType::Plain)
                         .title(" INFO ")
@@ -813,70 +1213,18 @@ pub fn draw_interface_mode(
                         ),
                 );
 
-                let middle_col = Paragraph::new(vec![
-                    Line::from(vec![
-                        Span::styled(
-                            " Errors      : ",
-                            Style::default().fg(app.current_theme.rx_area_color.key),
-                        ),
-                        Span::styled(
-                            format!("{}", interface_data.receive.errs),
-                            Style::default().fg(app.current_theme.rx_area_color.val),
-                        ),
-                    ]),
-                    Line::from(vec![
-                        Span::styled(
-                            " Drops       : ",
-                            Style::default().fg(app.current_theme.rx_area_color.key),
-                        ),
-                        Span::styled(
-                            format!("{}", interface_data.receive.drop),
-                            Style::default().fg(app.current_theme.rx_area_color.val),
-                        ),
-                    ]),
-                    Line::from(""),
-                    Line::from(vec![
-                        Span::styled(
-                            " FIFO        : ",
-                            Style::default().fg(app.current_theme.rx_area_color.key),
-                        ),
-                        Span::styled(
-                            format!("{}", interface_data.receive.fifo),
-                            Style::default().fg(app.current_theme.rx_area_color.val),
-                        ),
-                    ]),
-                    Line::from(vec![
-                        Span::styled(
-                            " Frame       : ",
-                            Style::default().fg(app.current_theme.rx_area_color.key),
-                        ),
-                        Span::styled(
-                            format!("{}", interface_data.receive.frame),
-                            Style::default().fg(app.current_theme.rx_area_color.val),
-                        ),
-                    ]),
-                    Line::from(""),
-                    Line::from(vec![
-                        Span::styled(
-                            " Compressed  : ",
-                            Style::default().fg(app.current_theme.rx_area_color.key),
-                        ),
-                        Span::styled(
-                            format!("{}", interface_data.receive.compressed),
-                            Style::default().fg(app.current_theme.rx_area_color.val),
-                        ),
-                    ]),
-                    Line::from(vec![
-                        Span::styled(
-                            " Multicast   : ",
-                            Style::default().fg(app.current_theme.rx_area_color.key),
-                        ),
-                        Span::styled(
-                            format!("{}", interface_data.receive.multicast),
-                            Style::default().fg(app.current_theme.rx_area_color.val),
-                        ),
-                    ]),
-                ])
+                let (rx_rates, tx_rates) = app
+                    .counter_rates
+                    .get(&selected_name)
+                    .cloned()
+                    .unwrap_or_default();
+                let middle_col = Paragraph::new(counter_lines(
+                    app,
+                    interface_data.receive.counters(),
+                    &rx_rates,
+                    app.current_theme.rx_area_color.key,
+                    app.current_theme.rx_area_color.val,
+                ))
                 .block(
                     Block::bordered()
                         .border_type(BorderType::Plain)
@@ -885,70 +1233,13 @@ pub fn draw_interface_mode(
                         .border_style(Style::default().fg(app.current_theme.rx_area_color.heading)),
                 );
 
-                let right_col = Paragraph::new(vec![
-                    Line::from(vec![
-                        Span::styled(
-                            " Errors       : ",
-                            Style::default().fg(app.current_theme.tx_area_color.key),
-                        ),
-                        Span::styled(
-                            format!("{}", interface_data.transmit.errs),
-                            Style::default().fg(app.current_theme.tx_area_color.val),
-                        ),
-                    ]),
-                    Line::from(vec![
-                        Span::styled(
-                            " Drops        : ",
-                            Style::default().fg(app.current_theme.tx_area_color.key),
-                        ),
-                        Span::styled(
-                            format!("{}", interface_data.transmit.drop),
-                            Style::default().fg(app.current_theme.tx_area_color.val),
-                        ),
-                    ]),
-                    Line::from(""),
-                    Line::from(vec![
-                        Span::styled(
-                            " FIFO         : ",
-                            Style::default().fg(app.current_theme.tx_area_color.key),
-                        ),
-                        Span::styled(
-                            format!("{}", interface_data.transmit.fifo),
-                            Style::default().fg(app.current_theme.tx_area_color.val),
-                        ),
-                    ]),
-                    Line::from(vec![
-                        Span::styled(
-                            " Collisions   : ",
-                            Style::default().fg(app.current_theme.tx_area_color.key),
-                        ),
-                        Span::styled(
-                            format!("{}", interface_data.transmit.colls),
-                            Style::default().fg(app.current_theme.tx_area_color.val),
-                        ),
-                    ]),
-                    Line::from(""),
-                    Line::from(vec![
-                        Span::styled(
-                            " Carrier      : ",
-                            Style::default().fg(app.current_theme.tx_area_color.key),
-                        ),
-                        Span::styled(
-                            format!("{}", interface_data.transmit.carrier),
-                            Style::default().fg(app.current_theme.tx_area_color.val),
-                        ),
-                    ]),
-                    Line::from(vec![
-                        Span::styled(
-                            " Compressed   : ",
-                            Style::default().fg(app.current_theme.tx_area_color.key),
-                        ),
-                        Span::styled(
-                            format!("{}", interface_data.transmit.compressed),
-                            Style::default().fg(app.current_theme.tx_area_color.val),
-                        ),
-                    ]),
-                ])
+                let right_col = Paragraph::new(counter_lines(
+                    app,
+                    interface_data.transmit.counters(),
+                    &tx_rates,
+                    app.current_theme.tx_area_color.key,
+                    app.current_theme.tx_area_color.val,
+                ))
                 .block(
                     Block::bordered()
                         .border_type(BorderType::Plain)
@@ -960,6 +1251,12 @@ pub fn draw_interface_mode(
                 frame.render_widget(left_col, stats_columns[0]);
                 frame.render_widget(middle_col, stats_columns[1]);
                 frame.render_widget(right_col, stats_columns[2]);
+
+                if let Some(wireless) = wireless {
+                    render_wireless_section(frame, stats_columns[3], app, wireless);
+                } else if has_members {
+                    render_members_section(frame, stats_columns[3], app, &interface_data);
+                }
             }
         }
         InterfaceSelected::All => {
@@ -1119,7 +1416,29 @@ pub fn draw_interface_mode(
             .block(
                 Block::bordered()
                     .border_type(BorderType::Plain)
-                    .title_top(Line::from(" OVERVIEW ").left_aligned())
+                    .title_top(
+                        Line::from(vec![
+                            Span::raw(" OVERVIEW "),
+                            Span::styled(
+                                "[n]",
+                                Style::default()
+                                    .fg(app.current_theme.overview_area_color.tick_highlight),
+                            ),
+                            Span::styled(
+                                " NetNS: ",
+                                Style::default()
+                                    .fg(app.current_theme.overview_area_color.tick_heading),
+                            ),
+                            Span::styled(
+                                app.netns.name.clone(),
+                                Style::default()
+                                    .fg(app.current_theme.overview_area_color.border)
+                                    .add_modifier(Modifier::BOLD),
+                            ),
+                            Span::raw(" "),
+                        ])
+                        .left_aligned(),
+                    )
                     .title_top(
                         Line::from(vec![
                             Span::styled(
@@ -1150,25 +1469,74 @@ pub fn draw_interface_mode(
         }
     }
 
-    let tcp_split =
-        Layout::horizontal([Constraint::Length(25), Constraint::Fill(1)]).split(tcp_area);
+    match app.panel {
+        Panel::Sockets => draw_socket_panel(app, frame, tcp_area, tcp_data, udp_data),
+        Panel::Processes => draw_process_panel(app, frame, tcp_area),
+        Panel::Recent => draw_recent_panel(app, frame, tcp_area),
+        Panel::Protocols => draw_protocol_panel(app, frame, tcp_area),
+        Panel::Routes => draw_route_panel(app, frame, tcp_area, tcp_data, udp_data),
+        Panel::Neighbors => draw_neighbor_panel(app, frame, tcp_area),
+        Panel::Conntrack => draw_conntrack_panel(app, frame, tcp_area),
+        Panel::Softnet => draw_softnet_panel(app, frame, tcp_area),
+        Panel::Irqs => draw_irq_panel(app, frame, tcp_area),
+    }
+
+    if app.show_help {
+        render_help_popup(frame, app);
+    }
+    if app.enter_tick_active {
+        draw_tick_mode(frame, app);
+    }
+    if app.edit_rx_mode || app.edit_tx_mode {
+        draw_speed_edit_popup(frame, app);
+    }
+    if app.change_theme {
+        theme_selection_popup(frame, app);
+    }
+    if let Mode::SelectingNetns { .. } = app.mode {
+        netns_selection_popup(frame, app);
+    }
+}
+
+fn draw_socket_panel(
+    app: &mut App,
+    frame: &mut Frame,
+    area: Rect,
+    tcp_data: &[TcpStats],
+    udp_data: &[UdpStats],
+) {
+    let tcp_border = if app.focus == Focus::TcpTable {
+        app.current_theme.tcpconn_area_color.filter
+    } else {
+        app.current_theme.tcpconn_area_color.border
+    };
+
+    let tcp_split = Layout::horizontal([Constraint::Length(27), Constraint::Fill(1)]).split(area);
+
+    let socket_view = app.socket_view;
+    let columns = SocketColumns::new(socket_view, app.socket_backend);
+    let mut sockets = collect_sockets(socket_view, tcp_data, udp_data);
+    app.socket_sort.sort(&mut sockets, &app.flow_rates);
+    let process_cache_arc = Arc::clone(&app.process_cache_arc);
+    let process_cache = process_cache_arc.lock().unwrap();
 
     match &app.mode {
         Mode::FilterLocalAddress { filter, index } => {
-            let hostname_cache = app.hostname_cache_arc.lock().unwrap();
-            let mut filtered_tcp: Vec<&TcpStats> = tcp_data
+            let locked = app.locked.as_ref().map(|l| (l.last.clone(), l.closed));
+            let mut hostname_cache = app.hostname_cache_arc.lock().unwrap();
+            let mut filtered_tcp: Vec<&dyn Socket> = sockets
                 .iter()
-                .filter(|conn| tcp_matches_filter(conn, filter, &hostname_cache))
+                .copied()
+                .filter(|conn| {
+                    socket_matches_filter(*conn, filter, &hostname_cache, &process_cache)
+                })
                 .collect();
 
-            let display_index = if let Some(selected_idx) = app.selected_index {
-                if selected_idx < filtered_tcp.len() {
-                    filtered_tcp = vec![filtered_tcp[selected_idx]];
-                    0
-                } else {
-                    app.selected_index = None;
-                    *index
-                }
+            // A locked connection is shown on its own, from its last snapshot once it has closed.
+            let closed = locked.as_ref().is_some_and(|(_, closed)| *closed);
+            let display_index = if let Some((snapshot, _)) = &locked {
+                filtered_tcp = vec![snapshot.socket()];
+                0
             } else {
                 *index
             };
@@ -1177,20 +1545,17 @@ pub fn draw_interface_mode(
                 std::collections::BTreeMap::new();
 
             for conn in filtered_tcp.iter() {
-                let state = tcp_state_name(conn.state);
+                let state = conn.state_name();
                 *state_counts.entry(state).or_insert(0) += 1;
 
-                let ip = conn.remote_ip;
-                if ip == [0, 0, 0, 0] || ip == [127, 0, 0, 1] {
+                let ip = conn.remote_ip();
+                if is_local_ip(&ip) {
                     continue;
                 }
 
                 let needs_lookup = !hostname_cache.contains_key(&ip);
                 if needs_lookup {
-                    app.hostname_cache_arc
-                        .lock()
-                        .unwrap()
-                        .insert(ip, "resolving...".to_string());
+                    hostname_cache.insert(ip, "resolving...".to_string());
 
                     let cache = Arc::clone(&app.hostname_cache_arc);
                     std::thread::spawn(move || {
@@ -1200,23 +1565,7 @@ pub fn draw_interface_mode(
                 }
             }
 
-            let unique_ips: HashSet<[u8; 4]> = tcp_data
-                .iter()
-                .filter(|c| c.remote_ip != [0, 0, 0, 0] && c.remote_ip != [127, 0, 0, 1])
-                .map(|c| c.remote_ip)
-                .collect();
-
-            let active = tcp_data
-                .iter()
-                .filter(|c| c.tx_queue > 0 || c.rx_queue > 0)
-                .count();
-            let local_only = tcp_data
-                .iter()
-                .filter(|c| c.remote_ip == [127, 0, 0, 1] || c.remote_ip == [0, 0, 0, 0])
-                .count();
-
-            let mut summary_lines =
-                return_summary_lines(app, tcp_data.len(), active, unique_ips, local_only);
+            let mut summary_lines = return_summary_lines(app, &sockets);
 
             for (state, count) in state_counts.iter() {
                 let color = match *state {
@@ -1244,20 +1593,41 @@ pub fn draw_interface_mode(
                 .fg(app.current_theme.tcpinfo_area_color.heading);
             frame.render_widget(summary, tcp_split[0]);
 
-            let tcp_rows: Vec<Row> = filtered_tcp
-                .iter()
+            // Matching connections that just closed stay on top as in the unfiltered table. The
+            // cursor only moves over the live ones below them.
+            let just_closed: Vec<(&dyn Socket, String)> = if locked.is_some() {
+                Vec::new()
+            } else {
+                just_closed_sockets(app, socket_view)
+                    .into_iter()
+                    .filter(|(conn, _)| {
+                        socket_matches_filter(*conn, filter, &hostname_cache, &process_cache)
+                    })
+                    .collect()
+            };
+            let display_index = display_index + just_closed.len();
+            let tcp_rows: Vec<Row> = just_closed
+                .into_iter()
+                .map(|(conn, process)| (conn, Some(process)))
+                .chain(filtered_tcp.iter().map(|conn| (*conn, None)))
                 .enumerate()
-                .map(|(i, conn)| {
+                .map(|(i, (conn, closed_process))| {
                     let hostname = hostname_cache
-                        .get(&conn.remote_ip)
+                        .get(&conn.remote_ip())
                         .cloned()
                         .unwrap_or_default();
 
-                    build_tcp_row(
+                    let row_closed = closed || closed_process.is_some();
+                    let process =
+                        closed_process.unwrap_or_else(|| process_label(conn, &process_cache));
+                    build_socket_row(
+                        app,
                         conn,
                         hostname,
+                        process,
                         i == display_index,
-                        &app.current_theme.tcpconn_area_color,
+                        row_closed,
+                        columns,
                     )
                 })
                 .collect();
@@ -1265,12 +1635,17 @@ pub fn draw_interface_mode(
             let total_rows = tcp_rows.len();
             let visible_rows = (tcp_split[1].height as usize).saturating_sub(4);
 
-            let mut scroll_offset = app.tcp_vertical_scroll;
+            // A locked connection is the only row, so the table starts at it.
+            let mut scroll_offset = if locked.is_some() {
+                0
+            } else {
+                app.tcp_vertical_scroll
+            };
 
-            if *index < scroll_offset {
-                scroll_offset = *index;
-            } else if *index >= scroll_offset + visible_rows {
-                scroll_offset = (*index + 1).saturating_sub(visible_rows);
+            if display_index < scroll_offset {
+                scroll_offset = display_index;
+            } else if display_index >= scroll_offset + visible_rows {
+                scroll_offset = (display_index + 1).saturating_sub(visible_rows);
             }
 
             app.tcp_vertical_scroll = scroll_offset;
@@ -1283,13 +1658,20 @@ pub fn draw_interface_mode(
                 .take(visible_rows)
                 .collect();
 
+            let keys = if closed {
+                "closed, Enter Esc"
+            } else if locked.is_some() {
+                "Enter Esc, g egress"
+            } else {
+                "↑ ↓ Enter Esc"
+            };
             let title = if filter.is_empty() {
-                format!(" Filter: * (↑ ↓ Enter Esc) ",)
+                format!(" Filter: * ({}) ", keys)
             } else {
-                format!(" Filter: {} (↑ ↓ Enter Esc) ", filter)
+                format!(" Filter: {} ({}) ", filter, keys)
             };
 
-            let tcp_table = build_tcp_table(visible_tcp_rows.clone())
+            let tcp_table = build_socket_table(visible_tcp_rows.clone(), columns, app.socket_sort)
                 .block(
                     Block::bordered()
                         .border_type(BorderType::Plain)
@@ -1316,7 +1698,9 @@ pub fn draw_interface_mode(
                 );
 
             let mut state = TableState::new();
-            let sel = (*index).min(visible_tcp_rows.len());
+            let sel = display_index
+                .saturating_sub(scroll_offset)
+                .min(visible_tcp_rows.len().saturating_sub(1));
             state.select(Some(sel));
 
             frame.render_stateful_widget(tcp_table, tcp_split[1], &mut state);
@@ -1333,19 +1717,31 @@ pub fn draw_interface_mode(
                 }),
                 &mut app.tcp_vertical_scroll_state,
             );
+
+            // The locked row stays visible above the popup.
+            if let Some((SocketSnapshot::Tcp(stats), closed)) = &locked {
+                let area = tcp_split[1];
+                let popup_area = Rect {
+                    x: area.x + 2,
+                    y: area.y + 4,
+                    width: area.width.saturating_sub(4),
+                    height: area.height.saturating_sub(5),
+                };
+                draw_tcp_health_popup(frame, app, popup_area, stats, *closed);
+            }
         }
 
         _ => {
             let mut state_counts: std::collections::BTreeMap<&str, usize> =
                 std::collections::BTreeMap::new();
 
-            for conn in tcp_data.iter() {
-                let state = tcp_state_name(conn.state);
+            for conn in sockets.iter() {
+                let state = conn.state_name();
                 *state_counts.entry(state).or_insert(0) += 1;
 
-                let ip = conn.remote_ip;
+                let ip = conn.remote_ip();
 
-                if ip == [0, 0, 0, 0] || ip == [127, 0, 0, 1] {
+                if is_local_ip(&ip) {
                     continue;
                 }
 
@@ -1368,23 +1764,7 @@ pub fn draw_interface_mode(
                 }
             }
 
-            let unique_ips: HashSet<[u8; 4]> = tcp_data
-                .iter()
-                .filter(|c| c.remote_ip != [0, 0, 0, 0] && c.remote_ip != [127, 0, 0, 1])
-                .map(|c| c.remote_ip)
-                .collect();
-
-            let active = tcp_data
-                .iter()
-                .filter(|c| c.tx_queue > 0 || c.rx_queue > 0)
-                .count();
-            let local_only = tcp_data
-                .iter()
-                .filter(|c| c.remote_ip == [127, 0, 0, 1] || c.remote_ip == [0, 0, 0, 0])
-                .count();
-
-            let mut summary_lines =
-                return_summary_lines(app, tcp_data.len(), active, unique_ips, local_only);
+            let mut summary_lines = return_summary_lines(app, &sockets);
 
             for (state, count) in state_counts.iter() {
                 let color = match *state {
@@ -1412,18 +1792,23 @@ pub fn draw_interface_mode(
                 .fg(app.current_theme.tcpinfo_area_color.heading);
             frame.render_widget(summary, tcp_split[0]);
 
-            let tcp_rows: Vec<Row> = tcp_data
-                .iter()
-                .map(|conn| {
+            let tcp_rows: Vec<Row> = just_closed_sockets(app, socket_view)
+                .into_iter()
+                .map(|(conn, process)| (conn, Some(process)))
+                .chain(sockets.iter().map(|conn| (*conn, None)))
+                .map(|(conn, closed_process)| {
                     let hostname = app
                         .hostname_cache_arc
                         .lock()
                         .unwrap()
-                        .get(&conn.remote_ip)
+                        .get(&conn.remote_ip())
                         .cloned()
                         .unwrap_or_default();
 
-                    build_tcp_row(conn, hostname, false, &app.current_theme.tcpconn_area_color)
+                    let closed = closed_process.is_some();
+                    let process =
+                        closed_process.unwrap_or_else(|| process_label(conn, &process_cache));
+                    build_socket_row(app, conn, hostname, process, false, closed, columns)
                 })
                 .collect();
 
@@ -1443,7 +1828,7 @@ pub fn draw_interface_mode(
                 .take(visible_rows)
                 .collect();
 
-            let tcp_table = build_tcp_table(visible_tcp_rows).block(
+            let tcp_table = build_socket_table(visible_tcp_rows, columns, app.socket_sort).block(
                 Block::bordered()
                     .border_type(BorderType::Plain)
                     .border_style(Style::default().fg(app.current_theme.tcpconn_area_color.border))
@@ -1452,7 +1837,16 @@ pub fn draw_interface_mode(
                             " [f] ",
                             Style::default().fg(tcp_border).add_modifier(Modifier::BOLD),
                         ),
-                        Span::from(format!("Tcp Connections ({}) ", tcp_data.len())),
+                        Span::from(format!("{} ({}) ", socket_view.title(), sockets.len())),
+                        Span::styled(
+                            "[u] ",
+                            Style::default().fg(tcp_border).add_modifier(Modifier::BOLD),
+                        ),
+                        Span::from(format!("sort: {} ", app.socket_sort.title())),
+                        Span::styled(
+                            "[s] ",
+                            Style::default().fg(tcp_border).add_modifier(Modifier::BOLD),
+                        ),
                     ]))
                     .padding(ratatui::widgets::Padding {
                         left: 1,
@@ -1478,18 +1872,1994 @@ pub fn draw_interface_mode(
             );
         }
     }
+}
+
+// Everything known about the locked TCP connection: the /proc/net/tcp timer columns, and
+// tcp_info, congestion control and socket memory queried from inet_diag every tick.
+fn draw_tcp_health_popup(frame: &mut Frame, app: &App, area: Rect, stats: &TcpStats, closed: bool) {
+    let info_colors = &app.current_theme.tcpinfo_area_color;
+    let alert = Style::default()
+        .fg(app.current_theme.info_area_color.changed)
+        .add_modifier(Modifier::BOLD);
+    let line = |key: &str, val: String, alerting: bool| {
+        Line::from(vec![
+            Span::styled(
+                format!(" {:<13}: ", key),
+                Style::default().fg(info_colors.key),
+            ),
+            Span::styled(
+                val,
+                if alerting {
+                    alert
+                } else {
+                    Style::default().fg(info_colors.val)
+                },
+            ),
+        ])
+    };
+    let heading = |title: &str| {
+        Line::from(Span::styled(
+            format!(" {}", title),
+            Style::default()
+                .fg(info_colors.heading)
+                .add_modifier(Modifier::BOLD),
+        ))
+    };
+    let details = app.tcp_details.as_ref();
+    let info = details.and_then(|d| d.info.as_ref());
+    let bytes = |b: u64| format_bytes(b, &app.byte_unit);
+
+    let timer = &stats.timer;
+    let timer_text = if timer.kind == 0 {
+        timer.name().to_string()
+    } else {
+        format!(
+            "{} ({:.1}s)",
+            timer.name(),
+            timer.expires_ms as f64 / 1000.0
+        )
+    };
+    let mut socket = vec![
+        heading("Socket"),
+        line("State", stats.state_name().to_string(), false),
+        line("Timer", timer_text, false),
+        line(
+            "Retransmits",
+            timer.retransmits.to_string(),
+            timer.retransmits > 0,
+        ),
+        line("Probes", timer.probes.to_string(), timer.probes > 0),
+        line(
+            "Congestion",
+            details
+                .and_then(|d| d.congestion.clone())
+                .unwrap_or("-".to_string()),
+            false,
+        ),
+    ];
+    if let Some(info) = info {
+        socket.extend([
+            line(
+                "CA state",
+                info.ca_state_name().to_string(),
+                info.ca_state != 0,
+            ),
+            line("Backoff", info.backoff.to_string(), info.backoff > 0),
+            line("RTO", format_rtt(info.rto_us), false),
+            line("MSS", info.snd_mss.to_string(), false),
+        ]);
+    }
+
+    let mut path = vec![heading("tcp_info")];
+    match info {
+        Some(info) => path.extend([
+            line(
+                "RTT / var",
+                format!(
+                    "{} / {}",
+                    format_rtt(info.rtt_us),
+                    format_rtt(info.rttvar_us)
+                ),
+                false,
+            ),
+            line("Min RTT", format_rtt(info.min_rtt_us), false),
+            line("Cwnd", info.snd_cwnd.to_string(), false),
+            // The kernel reports "infinite" until the first loss ends slow start.
+            line(
+                "Ssthresh",
+                if info.snd_ssthresh >= 0xffff {
+                    "-".to_string()
+                } else {
+                    info.snd_ssthresh.to_string()
+                },
+                false,
+            ),
+            line("Pacing rate", format_rate(info.pacing_rate as f64), false),
+            line(
+                "Delivery rate",
+                format_rate(info.delivery_rate as f64),
+                false,
+            ),
+            line("Unacked", info.unacked.to_string(), false),
+            line("Lost", info.lost.to_string(), info.lost > 0),
+            line("Retrans", info.retrans.to_string(), info.retrans > 0),
+            line("Total retrans", info.total_retrans.to_string(), false),
+        ]),
+        None => path.push(line("", "not available".to_string(), false)),
+    }
+
+    let mut memory = vec![heading("Bytes")];
+    if let Some(info) = info {
+        memory.extend([
+            line("Acked", bytes(info.bytes_acked), false),
+            line("Received", bytes(info.bytes_received), false),
+            line("Retransmitted", bytes(info.bytes_retrans), false),
+        ]);
+    }
+    memory.push(Line::from(""));
+    memory.push(heading("skmem"));
+    match details.and_then(|d| d.skmem.as_ref()) {
+        Some(mem) => memory.extend([
+            line(
+                "Recv / buf",
+                format!(
+                    "{} / {}",
+                    bytes(mem.rmem_alloc as u64),
+                    bytes(mem.rcvbuf as u64)
+                ),
+                false,
+            ),
+            line(
+                "Send / buf",
+                format!(
+                    "{} / {}",
+                    bytes(mem.wmem_alloc as u64),
+                    bytes(mem.sndbuf as u64)
+                ),
+                false,
+            ),
+            line("Queued", bytes(mem.wmem_queued as u64), false),
+            line("Fwd alloc", bytes(mem.fwd_alloc as u64), false),
+            line("Opt mem", bytes(mem.optmem as u64), false),
+            line("Backlog", bytes(mem.backlog as u64), false),
+            line("Drops", mem.drops.to_string(), mem.drops > 0),
+        ]),
+        None => memory.push(line("", "not available".to_string(), false)),
+    }
+
+    let block = Block::bordered()
+        .border_type(BorderType::Rounded)
+        .border_style(Style::default().fg(app.current_theme.tcpconn_area_color.filter))
+        .title(format!(
+            " TCP health: {} → {}{} ",
+            format_ip_port(&stats.local_ip, stats.local_port),
+            format_ip_port(&stats.remote_ip, stats.remote_port),
+            if closed { " (closed)" } else { "" }
+        ))
+        .title_style(Style::default().add_modifier(Modifier::BOLD));
+    let inner = block.inner(area);
+    frame.render_widget(Clear, area);
+    frame.render_widget(block, area);
+
+    let columns = Layout::horizontal([
+        Constraint::Ratio(1, 3),
+        Constraint::Ratio(1, 3),
+        Constraint::Ratio(1, 3),
+    ])
+    .split(inner);
+    for (lines, column) in [socket, path, memory].into_iter().zip(columns.iter()) {
+        frame.render_widget(Paragraph::new(lines), *column);
+    }
+}
+
+fn format_rate(bps: f64) -> String {
+    let (value, unit) = human_speed(bps);
+    format!("{:.1} {}", value, unit)
+}
+
+// Per-process TCP bandwidth, nethogs style. Enter shows the sockets of the process under the
+// cursor.
+fn draw_process_panel(app: &mut App, frame: &mut Frame, area: Rect) {
+    let split = Layout::horizontal([Constraint::Length(27), Constraint::Fill(1)]).split(area);
+    let colors = &app.current_theme.tcpconn_area_color;
+    let info = &app.current_theme.tcpinfo_area_color;
+    let rows = app.process_rows();
+
+    let key_val = |key: &'static str, val: String| {
+        Line::from(vec![
+            Span::styled(key, Style::default().fg(info.key)),
+            Span::styled(val, Style::default().fg(info.val)),
+        ])
+    };
+    let sum = |f: fn(&ProcessTraffic) -> f64| rows.iter().map(|p| f(p)).sum::<f64>();
+    let summary = Paragraph::new(vec![
+        key_val(" Processes   : ", rows.len().to_string()),
+        key_val(
+            " Connections : ",
+            rows.iter()
+                .map(|p| p.connections)
+                .sum::<usize>()
+                .to_string(),
+        ),
+        key_val(" RX          : ", format_rate(sum(|p| p.rx_rate))),
+        key_val(" TX          : ", format_rate(sum(|p| p.tx_rate))),
+        key_val(
+            " Session RX  : ",
+            format_bytes(sum(|p| p.rx_bytes as f64) as u64, &app.byte_unit),
+        ),
+        key_val(
+            " Session TX  : ",
+            format_bytes(sum(|p| p.tx_bytes as f64) as u64, &app.byte_unit),
+        ),
+        Line::from(""),
+        key_val(" Source      : ", "tcp_info".to_string()),
+    ])
+    .block(
+        Block::bordered()
+            .border_type(BorderType::Plain)
+            .title(" INFO ")
+            .title_style(Style::default().add_modifier(Modifier::BOLD)),
+    )
+    .fg(info.heading);
+    frame.render_widget(summary, split[0]);
+
+    let table_rows: Vec<Row> = rows
+        .iter()
+        .map(|p| {
+            let rate_style = |bps: f64| {
+                if bps > 0.0 {
+                    Style::default()
+                        .fg(colors.txrx_val)
+                        .add_modifier(Modifier::BOLD)
+                } else {
+                    Style::default().fg(colors.uid_val)
+                }
+            };
+            Row::new(vec![
+                Cell::from(Span::styled(
+                    p.pid.to_string(),
+                    Style::default().fg(colors.inode_val),
+                )),
+                Cell::from(Span::styled(
+                    p.comm.clone(),
+                    Style::default().fg(colors.hostname_val),
+                )),
+                Cell::from(Span::styled(
+                    p.uid.to_string(),
+                    Style::default().fg(colors.uid_val),
+                )),
+                Cell::from(Span::styled(format_rate(p.rx_rate), rate_style(p.rx_rate))),
+                Cell::from(Span::styled(format_rate(p.tx_rate), rate_style(p.tx_rate))),
+                Cell::from(Span::styled(
+                    format_bytes(p.rx_bytes, &app.byte_unit),
+                    Style::default().fg(colors.local_addr_val),
+                )),
+                Cell::from(Span::styled(
+                    format_bytes(p.tx_bytes, &app.byte_unit),
+                    Style::default().fg(colors.remote_addr_val),
+                )),
+                Cell::from(Span::styled(
+                    p.connections.to_string(),
+                    Style::default().fg(colors.uid_val),
+                )),
+            ])
+        })
+        .collect();
+
+    let total_rows = table_rows.len();
+    let visible_rows = (split[1].height as usize).saturating_sub(3);
+    let cursor = app.process_cursor(&rows);
+    let scroll = (cursor + 1).saturating_sub(visible_rows);
+    app.selected_process = rows.get(cursor).map(|p| p.pid);
+    app.tcp_vertical_scroll = cursor;
+    app.tcp_vertical_scroll_state = app
+        .tcp_vertical_scroll_state
+        .content_length(total_rows)
+        .position(cursor);
+    let visible: Vec<Row> = table_rows
+        .into_iter()
+        .skip(scroll)
+        .take(visible_rows)
+        .collect();
+
+    let focused = app.focus == Focus::TcpTable;
+    let border = if focused {
+        colors.filter
+    } else {
+        colors.border
+    };
+    let mut title = vec![
+        Span::raw(format!(" {} ({}) ", app.panel.title(), total_rows)),
+        Span::styled(
+            "[p] ",
+            Style::default().fg(border).add_modifier(Modifier::BOLD),
+        ),
+    ];
+    if focused {
+        title.push(Span::raw("sockets "));
+        title.push(Span::styled(
+            "[Enter] ",
+            Style::default().fg(border).add_modifier(Modifier::BOLD),
+        ));
+    }
+    let table = Table::new(
+        visible,
+        [
+            Constraint::Length(8),
+            Constraint::Percentage(20),
+            Constraint::Length(7),
+            Constraint::Length(12),
+            Constraint::Length(12),
+            Constraint::Length(12),
+            Constraint::Length(12),
+            Constraint::Fill(1),
+        ],
+    )
+    .header(
+        Row::new([
+            "PID", "Command", "UID", "RX/s ▾", "TX/s ▾", "RX Total", "TX Total", "Conns",
+        ])
+        .style(
+            Style::default()
+                .fg(Color::DarkGray)
+                .add_modifier(Modifier::BOLD),
+        ),
+    )
+    .block(
+        Block::bordered()
+            .border_type(BorderType::Plain)
+            .border_style(Style::default().fg(colors.border))
+            .title(Line::from(title))
+            .padding(ratatui::widgets::Padding {
+                left: 1,
+                right: 2,
+                top: 0,
+                bottom: 0,
+            }),
+    )
+    .row_highlight_style(
+        Style::default()
+            .bg(colors.filter_highlight_symbol)
+            .add_modifier(Modifier::BOLD),
+    );
+
+    let mut state = TableState::new();
+    if focused && total_rows > 0 {
+        state.select(Some(cursor - scroll));
+    }
+    frame.render_stateful_widget(table, split[1], &mut state);
+    frame.render_stateful_widget(
+        Scrollbar::new(ScrollbarOrientation::VerticalRight)
+            .begin_symbol(None)
+            .end_symbol(None)
+            .track_symbol(None)
+            .thumb_symbol("┃"),
+        split[1].inner(Margin {
+            vertical: 1,
+            horizontal: 0,
+        }),
+        &mut app.tcp_vertical_scroll_state,
+    );
+}
+
+// Closed connections, newest first, with when they were first and last seen and how long they
+// lived. Times are ages; a `>` marks connections that were already open at startup.
+fn draw_recent_panel(app: &mut App, frame: &mut Frame, area: Rect) {
+    let split = Layout::horizontal([Constraint::Length(27), Constraint::Fill(1)]).split(area);
+    let colors = &app.current_theme.tcpconn_area_color;
+    let info = &app.current_theme.tcpinfo_area_color;
+    let counts = app.lifecycle_counts;
+
+    let key_val = |key: &'static str, val: String| {
+        Line::from(vec![
+            Span::styled(key, Style::default().fg(info.key)),
+            Span::styled(val, Style::default().fg(info.val)),
+        ])
+    };
+    let summary = Paragraph::new(vec![
+        key_val(" Tracked     : ", app.connections.len().to_string()),
+        key_val(" Opened      : ", counts.opened.to_string()),
+        key_val(" State chg   : ", counts.state_changes.to_string()),
+        key_val(" Closed      : ", counts.closed.to_string()),
+        key_val(" History     : ", app.recent.len().to_string()),
+    ])
+    .block(
+        Block::bordered()
+            .border_type(BorderType::Plain)
+            .title(" INFO ")
+            .title_style(Style::default().add_modifier(Modifier::BOLD)),
+    )
+    .fg(info.heading);
+    frame.render_widget(summary, split[0]);
+
+    let hostname_cache = app.hostname_cache_arc.lock().unwrap();
+    let table_rows: Vec<Row> = app
+        .recent
+        .iter()
+        .map(|c| {
+            let conn = c.last.socket();
+            let at_start = if c.seen_at_start { ">" } else { "" };
+            let hostname = hostname_cache
+                .get(&conn.remote_ip())
+                .cloned()
+                .unwrap_or_default();
+            Row::new(vec![
+                Cell::from(Span::styled(
+                    conn.protocol().name(),
+                    Style::default().fg(colors.inode_val),
+                )),
+                Cell::from(Span::styled(
+                    format_ip_port(&conn.local_ip(), conn.local_port()),
+                    Style::default().fg(colors.local_addr_val),
+                )),
+                Cell::from(Span::styled(
+                    format_ip_port(&conn.remote_ip(), conn.remote_port()),
+                    Style::default().fg(colors.remote_addr_val),
+                )),
+                Cell::from(Span::styled(
+                    hostname,
+                    Style::default().fg(colors.hostname_val),
+                )),
+                Cell::from(Span::styled(
+                    c.process.clone().unwrap_or("-".to_string()),
+                    Style::default().fg(colors.hostname_val),
+                )),
+                Cell::from(Span::styled(
+                    format!(
+                        "{}{} ago",
+                        at_start,
+                        format_duration(c.first_seen.elapsed())
+                    ),
+                    Style::default().fg(colors.uid_val),
+                )),
+                Cell::from(Span::styled(
+                    format!("{} ago", format_duration(c.last_seen.elapsed())),
+                    Style::default().fg(colors.uid_val),
+                )),
+                Cell::from(Span::styled(
+                    format!(
+                        "{}{}",
+                        at_start,
+                        format_duration(c.last_seen.duration_since(c.first_seen))
+                    ),
+                    Style::default().fg(colors.txrx_val),
+                )),
+                Cell::from(Span::styled(
+                    conn.state_name(),
+                    Style::default().fg(colors.state_time_wait),
+                )),
+            ])
+        })
+        .collect();
+    drop(hostname_cache);
+
+    let total_rows = table_rows.len();
+    let visible_rows = (split[1].height as usize).saturating_sub(3);
+    let scroll = app
+        .tcp_vertical_scroll
+        .min(total_rows.saturating_sub(visible_rows));
+    app.tcp_vertical_scroll = scroll;
+    app.tcp_vertical_scroll_state = app
+        .tcp_vertical_scroll_state
+        .content_length(total_rows)
+        .position(scroll);
+    let visible: Vec<Row> = table_rows
+        .into_iter()
+        .skip(scroll)
+        .take(visible_rows)
+        .collect();
+
+    let border = if app.focus == Focus::TcpTable {
+        colors.filter
+    } else {
+        colors.border
+    };
+    let title = Line::from(vec![
+        Span::raw(format!(" {} ({}) ", app.panel.title(), total_rows)),
+        Span::styled(
+            "[p] ",
+            Style::default().fg(border).add_modifier(Modifier::BOLD),
+        ),
+    ]);
+    let table = Table::new(
+        visible,
+        [
+            Constraint::Length(5),
+            Constraint::Percentage(18),
+            Constraint::Percentage(18),
+            Constraint::Percentage(14),
+            Constraint::Percentage(12),
+            Constraint::Length(13),
+            Constraint::Length(12),
+            Constraint::Length(9),
+            Constraint::Fill(1),
+        ],
+    )
+    .header(
+        Row::new([
+            "Proto",
+            "Local",
+            "Remote",
+            "Host",
+            "Process",
+            "First seen",
+            "Last seen",
+            "Duration",
+            "Last state",
+        ])
+        .style(
+            Style::default()
+                .fg(Color::DarkGray)
+                .add_modifier(Modifier::BOLD),
+        ),
+    )
+    .block(
+        Block::bordered()
+            .border_type(BorderType::Plain)
+            .border_style(Style::default().fg(colors.border))
+            .title(title)
+            .padding(ratatui::widgets::Padding {
+                left: 1,
+                right: 2,
+                top: 0,
+                bottom: 0,
+            }),
+    );
+
+    frame.render_widget(table, split[1]);
+    frame.render_stateful_widget(
+        Scrollbar::new(ScrollbarOrientation::VerticalRight)
+            .begin_symbol(None)
+            .end_symbol(None)
+            .track_symbol(None)
+            .thumb_symbol("┃"),
+        split[1].inner(Margin {
+            vertical: 1,
+            horizontal: 0,
+        }),
+        &mut app.tcp_vertical_scroll_state,
+    );
+}
+
+// IPv4 and IPv6 routes with the number of listed connections each one currently carries.
+fn draw_route_panel(
+    app: &mut App,
+    frame: &mut Frame,
+    area: Rect,
+    tcp_data: &[TcpStats],
+    udp_data: &[UdpStats],
+) {
+    let split = Layout::horizontal([Constraint::Length(27), Constraint::Fill(1)]).split(area);
+    let colors = &app.current_theme.tcpconn_area_color;
+    let info = &app.current_theme.tcpinfo_area_color;
+
+    let mut conns = vec![0usize; app.routes.len()];
+    for conn in collect_sockets(app.socket_view, tcp_data, udp_data) {
+        let remote = conn.remote_ip().to_canonical();
+        if remote.is_unspecified() || remote.is_loopback() || app.local_addrs.contains(&remote) {
+            continue;
+        }
+        if let Some(route) = route_lookup(&app.routes, &remote) {
+            if let Some(i) = app.routes.iter().position(|r| std::ptr::eq(r, route)) {
+                conns[i] += 1;
+            }
+        }
+    }
+
+    let destination = |route: &Route| {
+        if route.prefix_len == 0 {
+            "default".to_string()
+        } else {
+            format!("{}/{}", route.destination, route.prefix_len)
+        }
+    };
+    let default_via = |v4: bool| {
+        app.routes
+            .iter()
+            .filter(|r| r.prefix_len == 0 && r.destination.is_ipv4() == v4)
+            .min_by_key(|r| r.metric)
+            .map(|r| r.interface.clone())
+            .unwrap_or("-".to_string())
+    };
+    let v4 = app
+        .routes
+        .iter()
+        .filter(|r| r.destination.is_ipv4())
+        .count();
+    let summary_line = |key: &'static str, val: String| {
+        Line::from(vec![
+            Span::styled(key, Style::default().fg(info.key)),
+            Span::styled(val, Style::default().fg(info.val)),
+        ])
+    };
+    let summary = Paragraph::new(vec![
+        summary_line(" IPv4 routes : ", v4.to_string()),
+        summary_line(" IPv6 routes : ", (app.routes.len() - v4).to_string()),
+        summary_line(" Default v4  : ", default_via(true)),
+        summary_line(" Default v6  : ", default_via(false)),
+    ])
+    .block(
+        Block::bordered()
+            .border_type(BorderType::Plain)
+            .title(" INFO ")
+            .title_style(Style::default().add_modifier(Modifier::BOLD)),
+    )
+    .fg(info.heading);
+    frame.render_widget(summary, split[0]);
+
+    let rows: Vec<Row> = app
+        .routes
+        .iter()
+        .zip(&conns)
+        .map(|(route, count)| {
+            Row::new(vec![
+                Cell::from(Span::styled(
+                    destination(route),
+                    Style::default().fg(colors.remote_addr_val),
+                )),
+                Cell::from(Span::styled(
+                    route
+                        .gateway
+                        .map(|g| g.to_string())
+                        .unwrap_or("-".to_string()),
+                    Style::default().fg(colors.local_addr_val),
+                )),
+                Cell::from(Span::styled(
+                    route.interface.clone(),
+                    Style::default().fg(colors.hostname_val),
+                )),
+                Cell::from(Span::styled(
+                    route.metric.to_string(),
+                    Style::default().fg(colors.uid_val),
+                )),
+                Cell::from(Span::styled(
+                    route_flags(route.flags),
+                    Style::default().fg(colors.inode_val),
+                )),
+                Cell::from(Span::styled(
+                    count.to_string(),
+                    if *count > 0 {
+                        Style::default()
+                            .fg(colors.txrx_val)
+                            .add_modifier(Modifier::BOLD)
+                    } else {
+                        Style::default().fg(colors.uid_val)
+                    },
+                )),
+            ])
+        })
+        .collect();
+
+    let total_rows = rows.len();
+    let visible_rows = (split[1].height as usize).saturating_sub(3);
+    app.tcp_vertical_scroll = app
+        .tcp_vertical_scroll
+        .min(total_rows.saturating_sub(visible_rows));
+    app.tcp_vertical_scroll_state = app
+        .tcp_vertical_scroll_state
+        .content_length(total_rows)
+        .position(app.tcp_vertical_scroll);
+    let visible: Vec<Row> = rows
+        .into_iter()
+        .skip(app.tcp_vertical_scroll)
+        .take(visible_rows)
+        .collect();
+
+    let border = if app.focus == Focus::TcpTable {
+        colors.filter
+    } else {
+        colors.border
+    };
+    let table = Table::new(
+        visible,
+        [
+            Constraint::Percentage(30),
+            Constraint::Percentage(25),
+            Constraint::Length(16),
+            Constraint::Length(11),
+            Constraint::Length(6),
+            Constraint::Fill(1),
+        ],
+    )
+    .header(
+        Row::new([
+            "Destination",
+            "Gateway",
+            "Interface",
+            "Metric",
+            "Flags",
+            "Conns",
+        ])
+        .style(
+            Style::default()
+                .fg(Color::DarkGray)
+                .add_modifier(Modifier::BOLD),
+        ),
+    )
+    .block(
+        Block::bordered()
+            .border_type(BorderType::Plain)
+            .border_style(Style::default().fg(colors.border))
+            .title(Line::from(vec![
+                Span::styled(
+                    " [p] ",
+                    Style::default().fg(border).add_modifier(Modifier::BOLD),
+                ),
+                Span::raw(format!("{} ({}) ", app.panel.title(), total_rows)),
+            ]))
+            .padding(ratatui::widgets::Padding {
+                left: 1,
+                right: 2,
+                top: 0,
+                bottom: 0,
+            }),
+    );
+    frame.render_widget(table, split[1]);
+    frame.render_stateful_widget(
+        Scrollbar::new(ScrollbarOrientation::VerticalRight)
+            .begin_symbol(None)
+            .end_symbol(None)
+            .track_symbol(None)
+            .thumb_symbol("┃"),
+        split[1].inner(Margin {
+            vertical: 1,
+            horizontal: 0,
+        }),
+        &mut app.tcp_vertical_scroll_state,
+    );
+}
+
+// ARP and IPv6 neighbour caches. Incomplete entries and entries whose MAC changed recently
+// (ARP spoofing, or a failover moving an address) are highlighted.
+fn draw_neighbor_panel(app: &mut App, frame: &mut Frame, area: Rect) {
+    let split = Layout::horizontal([Constraint::Length(27), Constraint::Fill(1)]).split(area);
+    let colors = &app.current_theme.tcpconn_area_color;
+    let info = &app.current_theme.tcpinfo_area_color;
+
+    let mut hostname_cache = app.hostname_cache_arc.lock().unwrap();
+
+    let (filter, index) = match &app.mode {
+        Mode::FilterNeighbors { filter, index } => (Some(filter.as_str()), *index),
+        _ => (None, 0),
+    };
+    let neighbors: Vec<&Neighbor> = app
+        .neighbors
+        .iter()
+        .filter(|n| {
+            neighbor_matches_filter(
+                n,
+                &app.mac_changes,
+                filter.unwrap_or_default(),
+                &hostname_cache,
+            )
+        })
+        .collect();
+
+    let v4 = app.neighbors.iter().filter(|n| n.ip.is_ipv4()).count();
+    let incomplete = app.neighbors.iter().filter(|n| n.incomplete).count();
+    let summary_line = |key: &'static str, val: usize, alert: bool| {
+        Line::from(vec![
+            Span::styled(key, Style::default().fg(info.key)),
+            Span::styled(
+                val.to_string(),
+                if alert && val > 0 {
+                    Style::default()
+                        .fg(colors.state_close_wait)
+                        .add_modifier(Modifier::BOLD)
+                } else {
+                    Style::default().fg(info.val)
+                },
+            ),
+        ])
+    };
+    let summary = Paragraph::new(vec![
+        summary_line(" Total       : ", app.neighbors.len(), false),
+        summary_line(" IPv4 / ARP  : ", v4, false),
+        summary_line(" IPv6        : ", app.neighbors.len() - v4, false),
+        summary_line(" Incomplete  : ", incomplete, true),
+        summary_line(" MAC changed : ", app.mac_changes.len(), true),
+    ])
+    .block(
+        Block::bordered()
+            .border_type(BorderType::Plain)
+            .title(" INFO ")
+            .title_style(Style::default().add_modifier(Modifier::BOLD)),
+    )
+    .fg(info.heading);
+    frame.render_widget(summary, split[0]);
+
+    let total_rows = neighbors.len();
+    let visible_rows = (split[1].height as usize).saturating_sub(3);
+    let mut scroll = app.tcp_vertical_scroll;
+    if filter.is_some() {
+        if index < scroll {
+            scroll = index;
+        } else if index >= scroll + visible_rows {
+            scroll = (index + 1).saturating_sub(visible_rows);
+        }
+    }
+    let scroll = scroll.min(total_rows.saturating_sub(visible_rows));
+    app.tcp_vertical_scroll = scroll;
+    app.tcp_vertical_scroll_state = app
+        .tcp_vertical_scroll_state
+        .content_length(total_rows)
+        .position(scroll);
+    let neighbors = &neighbors[scroll..(scroll + visible_rows).min(total_rows)];
+
+    // Only the rows on screen get a lookup; the placeholder keeps it from being spawned twice.
+    for neighbor in neighbors {
+        let ip = neighbor.ip;
+        if hostname_cache.contains_key(&ip) {
+            continue;
+        }
+        hostname_cache.insert(ip, "resolving...".to_string());
+        let cache = Arc::clone(&app.hostname_cache_arc);
+        std::thread::spawn(move || {
+            let hostname = resolve_hostname(&ip);
+            cache.lock().unwrap().insert(ip, hostname);
+        });
+    }
+
+    let visible: Vec<Row> = neighbors
+        .iter()
+        .map(|n| {
+            let changed = app.mac_changes.get(&(n.ip, n.device.clone()));
+            let state_style = match n.state {
+                _ if n.incomplete => Style::default()
+                    .fg(colors.state_close_wait)
+                    .add_modifier(Modifier::BOLD),
+                "REACHABLE" | "COMPLETE" | "PERMANENT" => {
+                    Style::default().fg(colors.state_established)
+                }
+                "STALE" => Style::default().fg(colors.state_time_wait),
+                _ => Style::default().fg(colors.state_fin_wait),
+            };
+            let mac_style = if changed.is_some() {
+                Style::default()
+                    .fg(colors.state_syn_sent)
+                    .add_modifier(Modifier::BOLD)
+            } else {
+                Style::default().fg(colors.local_addr_val)
+            };
+            let note = match changed {
+                Some((old, _)) => format!("MAC changed, was {}", old),
+                None if n.incomplete => "no reply".to_string(),
+                None => String::new(),
+            };
+
+            Row::new(vec![
+                Cell::from(Span::styled(
+                    n.ip.to_string(),
+                    Style::default().fg(colors.remote_addr_val),
+                )),
+                Cell::from(Span::styled(
+                    n.mac.clone().unwrap_or("-".to_string()),
+                    mac_style,
+                )),
+                Cell::from(Span::styled(
+                    n.device.clone(),
+                    Style::default().fg(colors.hostname_val),
+                )),
+                Cell::from(Span::styled(
+                    hostname_cache.get(&n.ip).cloned().unwrap_or_default(),
+                    Style::default().fg(colors.hostname_val),
+                )),
+                Cell::from(Span::styled(n.state, state_style)),
+                Cell::from(Span::styled(
+                    n.flags.clone(),
+                    Style::default().fg(colors.inode_val),
+                )),
+                Cell::from(Span::styled(note, mac_style)),
+            ])
+        })
+        .collect();
+
+    let border = if app.focus == Focus::TcpTable {
+        colors.filter
+    } else {
+        colors.border
+    };
+    let title = match filter {
+        Some("") => Line::from(" Filter: * (↑ ↓ Esc) "),
+        Some(filter) => Line::from(format!(" Filter: {} (↑ ↓ Esc) ", filter)),
+        None => Line::from(vec![
+            Span::styled(
+                " [f] ",
+                Style::default().fg(border).add_modifier(Modifier::BOLD),
+            ),
+            Span::raw(format!("{} ({}) ", app.panel.title(), total_rows)),
+            Span::styled(
+                "[p] ",
+                Style::default().fg(border).add_modifier(Modifier::BOLD),
+            ),
+        ]),
+    };
+    let table = Table::new(
+        visible,
+        [
+            Constraint::Percentage(20),
+            Constraint::Length(19),
+            Constraint::Length(12),
+            Constraint::Percentage(18),
+            Constraint::Length(11),
+            Constraint::Length(8),
+            Constraint::Fill(1),
+        ],
+    )
+    .header(
+        Row::new([
+            "IP Address",
+            "MAC",
+            "Device",
+            "Hostname",
+            "State",
+            "Flags",
+            "",
+        ])
+        .style(
+            Style::default()
+                .fg(Color::DarkGray)
+                .add_modifier(Modifier::BOLD),
+        ),
+    )
+    .block(
+        Block::bordered()
+            .border_type(BorderType::Plain)
+            .border_style(Style::default().fg(colors.border))
+            .title(title)
+            .padding(ratatui::widgets::Padding {
+                left: 1,
+                right: 2,
+                top: 0,
+                bottom: 0,
+            }),
+    )
+    .row_highlight_style(
+        Style::default()
+            .bg(colors.filter_highlight_symbol)
+            .add_modifier(Modifier::BOLD),
+    );
+
+    let mut state = TableState::new();
+    if filter.is_some() && total_rows > 0 {
+        state.select(Some(index.min(total_rows - 1) - scroll));
+    }
+    frame.render_stateful_widget(table, split[1], &mut state);
+    frame.render_stateful_widget(
+        Scrollbar::new(ScrollbarOrientation::VerticalRight)
+            .begin_symbol(None)
+            .end_symbol(None)
+            .track_symbol(None)
+            .thumb_symbol("┃"),
+        split[1].inner(Margin {
+            vertical: 1,
+            horizontal: 0,
+        }),
+        &mut app.tcp_vertical_scroll_state,
+    );
+}
+
+// Netfilter's connection tracking table: forwarded and NATed flows that have no local socket.
+fn draw_conntrack_panel(app: &mut App, frame: &mut Frame, area: Rect) {
+    let split = Layout::horizontal([Constraint::Length(27), Constraint::Fill(1)]).split(area);
+    let colors = &app.current_theme.tcpconn_area_color;
+    let info = &app.current_theme.tcpinfo_area_color;
+
+    let (filter, index) = match &app.mode {
+        Mode::FilterConntrack { filter, index } => (Some(filter.as_str()), *index),
+        _ => (None, 0),
+    };
+    let entries: Vec<&ConntrackEntry> = app
+        .conntrack
+        .iter()
+        .filter(|e| conntrack_matches_filter(e, filter.unwrap_or_default()))
+        .collect();
+
+    let key_val = |key: &'static str, val: String, style: Style| {
+        Line::from(vec![
+            Span::styled(key, Style::default().fg(info.key)),
+            Span::styled(val, style),
+        ])
+    };
+    let val_style = Style::default().fg(info.val);
+    let alert_style = Style::default()
+        .fg(app.current_theme.info_area_color.changed)
+        .add_modifier(Modifier::BOLD);
+
+    let mut summary_lines = vec![key_val(
+        " Entries     : ",
+        app.conntrack.len().to_string(),
+        val_style,
+    )];
+    match app.conntrack_usage {
+        Some((count, max)) if max > 0 => {
+            let usage = count as f64 / max as f64;
+            let style = if usage >= 0.8 { alert_style } else { val_style };
+            summary_lines.push(key_val(
+                " Table       : ",
+                format!("{}/{}", count, max),
+                val_style,
+            ));
+            summary_lines.push(Line::from(vec![
+                Span::raw(" "),
+                Span::styled(make_bar(usage, 16), style),
+                Span::styled(format!(" {:.1}%", usage * 100.0), style),
+            ]));
+        }
+        _ => summary_lines.push(key_val(
+            " Table       : ",
+            "not loaded".to_string(),
+            val_style,
+        )),
+    }
+    let accounting = match app.conntrack.first() {
+        Some(e) if e.bytes().is_some() => "on",
+        Some(_) => "off",
+        None => "-",
+    };
+    summary_lines.extend([
+        key_val(
+            " Source      : ",
+            app.conntrack_backend.name().to_string(),
+            val_style,
+        ),
+        key_val(" Accounting  : ", accounting.to_string(), val_style),
+        Line::from(""),
+        key_val(
+            " SNAT / DNAT : ",
+            format!(
+                "{} / {}",
+                app.conntrack.iter().filter(|e| e.snat()).count(),
+                app.conntrack.iter().filter(|e| e.dnat()).count()
+            ),
+            val_style,
+        ),
+        key_val(
+            " Assured     : ",
+            app.conntrack
+                .iter()
+                .filter(|e| e.assured)
+                .count()
+                .to_string(),
+            val_style,
+        ),
+        key_val(
+            " Unreplied   : ",
+            app.conntrack
+                .iter()
+                .filter(|e| e.unreplied)
+                .count()
+                .to_string(),
+            val_style,
+        ),
+        Line::from(""),
+        key_val(
+            " Sort [s]    : ",
+            app.conntrack_sort.title().to_string(),
+            val_style,
+        ),
+    ]);
+    let summary = Paragraph::new(summary_lines)
+        .block(
+            Block::bordered()
+                .border_type(BorderType::Plain)
+                .title(" INFO ")
+                .title_style(Style::default().add_modifier(Modifier::BOLD)),
+        )
+        .fg(info.heading);
+    frame.render_widget(summary, split[0]);
+
+    let total_rows = entries.len();
+    let visible_rows = (split[1].height as usize).saturating_sub(3);
+    let mut scroll = app.tcp_vertical_scroll;
+    if filter.is_some() {
+        if index < scroll {
+            scroll = index;
+        } else if index >= scroll + visible_rows {
+            scroll = (index + 1).saturating_sub(visible_rows);
+        }
+    }
+    let scroll = scroll.min(total_rows.saturating_sub(visible_rows));
+    app.tcp_vertical_scroll = scroll;
+    app.tcp_vertical_scroll_state = app
+        .tcp_vertical_scroll_state
+        .content_length(total_rows)
+        .position(scroll);
+
+    let counter = |value: Option<u64>, bytes: bool| match value {
+        Some(v) if bytes && !app.raw_bytes => format_bytes(v, &app.byte_unit),
+        Some(v) => v.to_string(),
+        None => "-".to_string(),
+    };
+    let rows: Vec<Row> = entries
+        .iter()
+        .skip(scroll)
+        .take(visible_rows)
+        .map(|e| {
+            let state = e.state.clone().unwrap_or_default();
+            let state_style = match state.as_str() {
+                "ESTABLISHED" => Style::default().fg(colors.state_established),
+                "TIME_WAIT" | "CLOSE" => Style::default().fg(colors.state_time_wait),
+                "CLOSE_WAIT" | "LAST_ACK" => Style::default().fg(colors.state_close_wait),
+                "SYN_SENT" | "SYN_RECV" | "SYN_SENT2" => Style::default().fg(colors.state_syn_sent),
+                _ => Style::default().fg(colors.state_fin_wait),
+            };
+            // The translated side of the flow, as the reply tuple sees it.
+            let mut nat = Vec::new();
+            if e.snat() {
+                nat.push(format!(
+                    "SNAT {}",
+                    conntrack_endpoint(&e.reply.dst, e.reply.dport)
+                ));
+            }
+            if e.dnat() {
+                nat.push(format!(
+                    "DNAT {}",
+                    conntrack_endpoint(&e.reply.src, e.reply.sport)
+                ));
+            }
+            let mut flags = Vec::new();
+            if e.assured {
+                flags.push("A".to_string());
+            }
+            if e.unreplied {
+                flags.push("U".to_string());
+            }
+            if e.mark != 0 {
+                flags.push(format!("mark={}", e.mark));
+            }
+
+            Row::new(vec![
+                Cell::from(Span::styled(
+                    e.protocol.clone(),
+                    Style::default().fg(colors.inode_val),
+                )),
+                Cell::from(Span::styled(state, state_style)),
+                Cell::from(Span::styled(
+                    conntrack_endpoint(&e.original.src, e.original.sport),
+                    Style::default().fg(colors.local_addr_val),
+                )),
+                Cell::from(Span::styled(
+                    conntrack_endpoint(&e.original.dst, e.original.dport),
+                    Style::default().fg(colors.remote_addr_val),
+                )),
+                Cell::from(Span::styled(
+                    nat.join(" "),
+                    Style::default().fg(colors.hostname_val),
+                )),
+                Cell::from(Span::styled(
+                    format!("{}s", e.timeout),
+                    Style::default().fg(colors.inode_val),
+                )),
+                Cell::from(Span::styled(
+                    counter(e.packets(), false),
+                    Style::default().fg(colors.inode_val),
+                )),
+                Cell::from(Span::styled(
+                    counter(e.bytes(), true),
+                    Style::default().fg(colors.inode_val),
+                )),
+                Cell::from(Span::styled(
+                    flags.join(" "),
+                    Style::default().fg(colors.inode_val),
+                )),
+            ])
+        })
+        .collect();
+
+    let border = if app.focus == Focus::TcpTable {
+        colors.filter
+    } else {
+        colors.border
+    };
+    let title = match filter {
+        Some("") => Line::from(" Filter: * (↑ ↓ Esc) "),
+        Some(filter) => Line::from(format!(" Filter: {} (↑ ↓ Esc) ", filter)),
+        None => Line::from(vec![
+            Span::styled(
+                " [f] ",
+                Style::default().fg(border).add_modifier(Modifier::BOLD),
+            ),
+            Span::raw(format!("{} ({}) ", app.panel.title(), total_rows)),
+            Span::styled(
+                "[p] ",
+                Style::default().fg(border).add_modifier(Modifier::BOLD),
+            ),
+        ]),
+    };
+    let sorted = match app.conntrack_sort {
+        ConntrackSort::Bytes => 7,
+        ConntrackSort::Packets => 6,
+        ConntrackSort::Timeout => 5,
+        ConntrackSort::Protocol => 0,
+        ConntrackSort::State => 1,
+        ConntrackSort::Source => 2,
+        ConntrackSort::Destination => 3,
+    };
+    let header = [
+        "Proto",
+        "State",
+        "Source",
+        "Destination",
+        "NAT",
+        "Timeout",
+        "Packets",
+        "Bytes",
+        "Flags",
+    ]
+    .iter()
+    .enumerate()
+    .map(|(i, name)| {
+        if i == sorted {
+            format!("{} ▾", name)
+        } else {
+            name.to_string()
+        }
+    });
+    let table = Table::new(
+        rows,
+        [
+            Constraint::Length(8),
+            Constraint::Length(12),
+            Constraint::Percentage(20),
+            Constraint::Percentage(20),
+            Constraint::Percentage(20),
+            Constraint::Length(9),
+            Constraint::Length(9),
+            Constraint::Length(11),
+            Constraint::Fill(1),
+        ],
+    )
+    .header(
+        Row::new(header).style(
+            Style::default()
+                .fg(Color::DarkGray)
+                .add_modifier(Modifier::BOLD),
+        ),
+    )
+    .block(
+        Block::bordered()
+            .border_type(BorderType::Plain)
+            .border_style(Style::default().fg(colors.border))
+            .title(title)
+            .padding(ratatui::widgets::Padding {
+                left: 1,
+                right: 2,
+                top: 0,
+                bottom: 0,
+            }),
+    )
+    .row_highlight_style(
+        Style::default()
+            .bg(colors.filter_highlight_symbol)
+            .add_modifier(Modifier::BOLD),
+    );
+
+    let mut state = TableState::new();
+    if filter.is_some() && total_rows > 0 {
+        state.select(Some(index.min(total_rows - 1) - scroll));
+    }
+    frame.render_stateful_widget(table, split[1], &mut state);
+    frame.render_stateful_widget(
+        Scrollbar::new(ScrollbarOrientation::VerticalRight)
+            .begin_symbol(None)
+            .end_symbol(None)
+            .track_symbol(None)
+            .thumb_symbol("┃"),
+        split[1].inner(Margin {
+            vertical: 1,
+            horizontal: 0,
+        }),
+        &mut app.tcp_vertical_scroll_state,
+    );
+}
+
+// Per-CPU packet processing. Drops here mean a CPU's input backlog overflowed, squeezes that
+// NET_RX ran out of budget with work left; bars are scaled to the busiest CPU so an IRQ or RPS
+// imbalance stands out.
+fn draw_softnet_panel(app: &mut App, frame: &mut Frame, area: Rect) {
+    let split = Layout::horizontal([Constraint::Length(27), Constraint::Fill(1)]).split(area);
+    let colors = &app.current_theme.tcpconn_area_color;
+    let info = &app.current_theme.tcpinfo_area_color;
+    let changed = app.current_theme.info_area_color.changed;
+
+    let Some((now, stats)) = &app.cpu_net_stats else {
+        return;
+    };
+    let prev = app.prev_cpu_net_stats.as_ref();
+    let dt = prev.map(|(t, _)| now - t).filter(|dt| *dt > 0.0);
+    let prev_by_cpu: HashMap<usize, &CpuNetStats> = prev
+        .map(|(_, p)| p.iter().map(|c| (c.cpu, c)).collect())
+        .unwrap_or_default();
+
+    // (cpu stats, NET_RX/s, NET_TX/s, processed/s, dropped/s, squeezed/s, rps/s)
+    let rates: Vec<(&CpuNetStats, [f64; 6])> = stats
+        .iter()
+        .map(|cpu| {
+            let before = prev_by_cpu.get(&cpu.cpu);
+            let rate = |get: fn(&CpuNetStats) -> u64| match (before, dt) {
+                (Some(before), Some(dt)) => get(cpu).saturating_sub(get(before)) as f64 / dt,
+                _ => 0.0,
+            };
+            (
+                cpu,
+                [
+                    rate(|c| c.net_rx),
+                    rate(|c| c.net_tx),
+                    rate(|c| c.processed),
+                    rate(|c| c.dropped),
+                    rate(|c| c.time_squeeze),
+                    rate(|c| c.received_rps),
+                ],
+            )
+        })
+        .collect();
+    let total = |i: usize| rates.iter().map(|(_, r)| r[i]).sum::<f64>();
+    let max = |i: usize| rates.iter().map(|(_, r)| r[i]).fold(0.0, f64::max);
+
+    let val_style = Style::default().fg(info.val);
+    let alert_style = Style::default().fg(changed).add_modifier(Modifier::BOLD);
+    let key_val = |key: &'static str, val: String, style: Style| {
+        Line::from(vec![
+            Span::styled(key, Style::default().fg(info.key)),
+            Span::styled(val, style),
+        ])
+    };
+    let alert_if = |value: f64| if value > 0.0 { alert_style } else { val_style };
+    let busiest = rates
+        .iter()
+        .max_by(|a, b| a.1[0].total_cmp(&b.1[0]))
+        .filter(|_| total(0) > 0.0)
+        .map(|(cpu, r)| format!("CPU{} {:.0}%", cpu.cpu, r[0] / total(0) * 100.0))
+        .unwrap_or("-".to_string());
+
+    let summary = Paragraph::new(vec![
+        key_val(" CPUs        : ", stats.len().to_string(), val_style),
+        Line::from(""),
+        key_val(" NET_RX      : ", format!("{:.0}/s", total(0)), val_style),
+        key_val(" NET_TX      : ", format!("{:.0}/s", total(1)), val_style),
+        key_val(" Processed   : ", format!("{:.0}/s", total(2)), val_style),
+        key_val(
+            " Dropped     : ",
+            format!("{:.1}/s", total(3)),
+            alert_if(total(3)),
+        ),
+        key_val(
+            " Squeezed    : ",
+            format!("{:.1}/s", total(4)),
+            alert_if(total(4)),
+        ),
+        Line::from(""),
+        key_val(
+            " Drops total : ",
+            stats.iter().map(|c| c.dropped).sum::<u64>().to_string(),
+            val_style,
+        ),
+        key_val(
+            " Squeezes    : ",
+            stats
+                .iter()
+                .map(|c| c.time_squeeze)
+                .sum::<u64>()
+                .to_string(),
+            val_style,
+        ),
+        key_val(" Busiest RX  : ", busiest, val_style),
+    ])
+    .block(
+        Block::bordered()
+            .border_type(BorderType::Plain)
+            .title(" INFO ")
+            .title_style(Style::default().add_modifier(Modifier::BOLD)),
+    )
+    .fg(info.heading);
+    frame.render_widget(summary, split[0]);
+
+    let total_rows = rates.len();
+    let visible_rows = (split[1].height as usize).saturating_sub(3);
+    let scroll = app
+        .tcp_vertical_scroll
+        .min(total_rows.saturating_sub(visible_rows));
+    app.tcp_vertical_scroll = scroll;
+    app.tcp_vertical_scroll_state = app
+        .tcp_vertical_scroll_state
+        .content_length(total_rows)
+        .position(scroll);
+
+    const BAR_WIDTH: usize = 16;
+    let bar = |value: f64, max: f64| {
+        let fraction = if max > 0.0 { value / max } else { 0.0 };
+        make_bar(fraction, BAR_WIDTH)
+    };
+    let (max_rx, max_processed) = (max(0), max(2));
+    let rows: Vec<Row> = rates
+        .iter()
+        .skip(scroll)
+        .take(visible_rows)
+        .map(|(cpu, r)| {
+            let counter = |total: u64, rate: f64| {
+                let text = if rate > 0.0 {
+                    format!("{} +{:.1}/s", total, rate)
+                } else {
+                    total.to_string()
+                };
+                Span::styled(text, alert_if(rate))
+            };
+            Row::new(vec![
+                Cell::from(Span::styled(
+                    format!("CPU{}", cpu.cpu),
+                    Style::default().fg(colors.local_addr_val),
+                )),
+                Cell::from(Line::from(vec![
+                    Span::styled(
+                        bar(r[0], max_rx),
+                        Style::default().fg(colors.state_established),
+                    ),
+                    Span::styled(
+                        format!(" {:>8.0}", r[0]),
+                        Style::default().fg(colors.remote_addr_val),
+                    ),
+                ])),
+                Cell::from(Span::styled(
+                    format!("{:>8.0}", r[1]),
+                    Style::default().fg(colors.remote_addr_val),
+                )),
+                Cell::from(Line::from(vec![
+                    Span::styled(
+                        bar(r[2], max_processed),
+                        Style::default().fg(colors.state_established),
+                    ),
+                    Span::styled(
+                        format!(" {:>8.0}", r[2]),
+                        Style::default().fg(colors.remote_addr_val),
+                    ),
+                ])),
+                Cell::from(counter(cpu.dropped, r[3])),
+                Cell::from(counter(cpu.time_squeeze, r[4])),
+                Cell::from(Span::styled(
+                    format!("{:.0}", r[5]),
+                    Style::default().fg(colors.inode_val),
+                )),
+                Cell::from(Span::styled(
+                    cpu.flow_limit.to_string(),
+                    Style::default().fg(colors.inode_val),
+                )),
+                Cell::from(Span::styled(
+                    cpu.backlog.map_or("-".to_string(), |b| b.to_string()),
+                    Style::default().fg(colors.inode_val),
+                )),
+            ])
+        })
+        .collect();
+
+    let border = if app.focus == Focus::TcpTable {
+        colors.filter
+    } else {
+        colors.border
+    };
+    let table = Table::new(
+        rows,
+        [
+            Constraint::Length(7),
+            Constraint::Length(BAR_WIDTH as u16 + 9),
+            Constraint::Length(9),
+            Constraint::Length(BAR_WIDTH as u16 + 9),
+            Constraint::Length(18),
+            Constraint::Length(18),
+            Constraint::Length(8),
+            Constraint::Length(9),
+            Constraint::Fill(1),
+        ],
+    )
+    .header(
+        Row::new([
+            "CPU",
+            "NET_RX/s",
+            "NET_TX/s",
+            "Processed/s",
+            "Dropped",
+            "Squeezed",
+            "RPS/s",
+            "Flow lim",
+            "Backlog",
+        ])
+        .style(
+            Style::default()
+                .fg(Color::DarkGray)
+                .add_modifier(Modifier::BOLD),
+        ),
+    )
+    .block(
+        Block::bordered()
+            .border_type(BorderType::Plain)
+            .border_style(Style::default().fg(colors.border))
+            .title(Line::from(vec![
+                Span::raw(format!(" {} ({}) ", app.panel.title(), total_rows)),
+                Span::styled(
+                    "[p] ",
+                    Style::default().fg(border).add_modifier(Modifier::BOLD),
+                ),
+            ]))
+            .padding(ratatui::widgets::Padding {
+                left: 1,
+                right: 2,
+                top: 0,
+                bottom: 0,
+            }),
+    );
+    frame.render_widget(table, split[1]);
+    frame.render_stateful_widget(
+        Scrollbar::new(ScrollbarOrientation::VerticalRight)
+            .begin_symbol(None)
+            .end_symbol(None)
+            .track_symbol(None)
+            .thumb_symbol("┃"),
+        split[1].inner(Margin {
+            vertical: 1,
+            horizontal: 0,
+        }),
+        &mut app.tcp_vertical_scroll_state,
+    );
+}
+
+// One interface's IRQs with their per-CPU interrupt rates, and its rx/tx queues with RPS/XPS
+// masks and whatever per-queue counters the driver reports.
+fn draw_irq_panel(app: &App, frame: &mut Frame, area: Rect) {
+    let split = Layout::horizontal([Constraint::Length(27), Constraint::Fill(1)]).split(area);
+    let colors = &app.current_theme.tcpconn_area_color;
+    let info = &app.current_theme.tcpinfo_area_color;
+    let val_style = Style::default().fg(info.val);
+    let key_val = |key: &'static str, val: String| {
+        Line::from(vec![
+            Span::styled(key, Style::default().fg(info.key)),
+            Span::styled(val, val_style),
+        ])
+    };
+    let header_style = Style::default()
+        .fg(Color::DarkGray)
+        .add_modifier(Modifier::BOLD);
+    let block = |title: String| {
+        Block::bordered()
+            .border_type(BorderType::Plain)
+            .border_style(Style::default().fg(colors.border))
+            .title(title)
+            .padding(ratatui::widgets::Padding {
+                left: 1,
+                right: 1,
+                top: 0,
+                bottom: 0,
+            })
+    };
+
+    let interface = app.net_queues.as_ref().map(|(_, name, _)| name.clone());
+    let irqs: &[IrqStats] = app
+        .irq_stats
+        .as_ref()
+        .map(|(_, irqs)| irqs.as_slice())
+        .unwrap_or_default();
+
+    // Per IRQ and CPU, interrupts per second since the previous tick.
+    let irq_dt = match (&app.irq_stats, &app.prev_irq_stats) {
+        (Some((now, _)), Some((then, _))) if now > then => Some(now - then),
+        _ => None,
+    };
+    let prev_irqs: HashMap<u32, &IrqStats> = app
+        .prev_irq_stats
+        .as_ref()
+        .map(|(_, p)| p.iter().map(|i| (i.irq, i)).collect())
+        .unwrap_or_default();
+    let irq_rates: Vec<Vec<f64>> = irqs
+        .iter()
+        .map(|irq| {
+            irq.counts
+                .iter()
+                .enumerate()
+                .map(|(i, count)| {
+                    match (
+                        prev_irqs.get(&irq.irq).and_then(|p| p.counts.get(i)),
+                        irq_dt,
+                    ) {
+                        (Some(before), Some(dt)) => count.saturating_sub(*before) as f64 / dt,
+                        _ => 0.0,
+                    }
+                })
+                .collect()
+        })
+        .collect();
+    let per_cpu: Vec<f64> = (0..app.irq_cpus.len())
+        .map(|i| irq_rates.iter().filter_map(|r| r.get(i)).sum())
+        .collect();
+    let per_cpu_max = per_cpu.iter().copied().fold(0.0, f64::max);
+
+    let queues: &[NetQueue] = app
+        .net_queues
+        .as_ref()
+        .map(|(_, _, q)| q.as_slice())
+        .unwrap_or_default();
+    let rx_queues = queues.iter().filter(|q| q.name.starts_with("rx-")).count();
+
+    let mut summary_lines = vec![
+        key_val(
+            " Interface   : ",
+            interface.clone().unwrap_or("-".to_string()),
+        ),
+        key_val(
+            " Driver      : ",
+            interface
+                .as_deref()
+                .filter(|_| app.in_home_netns())
+                .and_then(interface_driver)
+                .unwrap_or("-".to_string()),
+        ),
+        key_val(" IRQs        : ", irqs.len().to_string()),
+        key_val(
+            " Queues rx/tx: ",
+            format!("{}/{}", rx_queues, queues.len() - rx_queues),
+        ),
+        key_val(
+            " IRQ/s       : ",
+            format!("{:.0}", per_cpu.iter().sum::<f64>()),
+        ),
+        Line::from(""),
+    ];
+    for (cpu, rate) in app.irq_cpus.iter().zip(&per_cpu) {
+        let fraction = if per_cpu_max > 0.0 {
+            rate / per_cpu_max
+        } else {
+            0.0
+        };
+        summary_lines.push(Line::from(vec![
+            Span::styled(format!(" CPU{:<4}", cpu), Style::default().fg(info.key)),
+            Span::styled(
+                make_bar(fraction, 8),
+                Style::default().fg(colors.state_established),
+            ),
+            Span::styled(format!(" {:>7.0}", rate), val_style),
+        ]));
+    }
+    let summary = Paragraph::new(summary_lines)
+        .block(
+            Block::bordered()
+                .border_type(BorderType::Plain)
+                .title(" INFO ")
+                .title_style(Style::default().add_modifier(Modifier::BOLD)),
+        )
+        .fg(info.heading);
+    frame.render_widget(summary, split[0]);
+
+    let irq_height = (irqs.len() as u16 + 3).clamp(4, split[1].height / 2);
+    let [irq_area, queue_area] =
+        Layout::vertical([Constraint::Length(irq_height), Constraint::Fill(1)]).areas(split[1]);
+
+    // One cell per CPU column, scaled to the IRQ's busiest CPU.
+    const LEVELS: [char; 8] = ['▁', '▂', '▃', '▄', '▅', '▆', '▇', '█'];
+    let irq_rows: Vec<Row> = irqs
+        .iter()
+        .zip(&irq_rates)
+        .map(|(irq, rates)| {
+            let max = rates.iter().copied().fold(0.0, f64::max);
+            let heat: String = rates
+                .iter()
+                .map(|r| {
+                    if *r <= 0.0 {
+                        '·'
+                    } else {
+                        LEVELS[((r / max) * 7.0).round() as usize]
+                    }
+                })
+                .collect();
+            Row::new(vec![
+                Cell::from(Span::styled(
+                    irq.irq.to_string(),
+                    Style::default().fg(colors.inode_val),
+                )),
+                Cell::from(Span::styled(
+                    irq.actions.clone(),
+                    Style::default().fg(colors.local_addr_val),
+                )),
+                Cell::from(Span::styled(
+                    app.irq_affinity.get(&irq.irq).cloned().unwrap_or_default(),
+                    Style::default().fg(colors.hostname_val),
+                )),
+                Cell::from(Span::styled(
+                    format!("{:.0}", rates.iter().sum::<f64>()),
+                    Style::default().fg(colors.remote_addr_val),
+                )),
+                Cell::from(Span::styled(
+                    irq.counts.iter().sum::<u64>().to_string(),
+                    Style::default().fg(colors.inode_val),
+                )),
+                Cell::from(Span::styled(
+                    heat,
+                    Style::default().fg(colors.state_established),
+                )),
+            ])
+        })
+        .collect();
+    let irq_title = match &interface {
+        Some(name) => format!(" [p] {}: {} ", app.panel.title(), name),
+        None => format!(" [p] {} ", app.panel.title()),
+    };
+    let irq_table = Table::new(
+        irq_rows,
+        [
+            Constraint::Length(6),
+            Constraint::Percentage(30),
+            Constraint::Length(10),
+            Constraint::Length(9),
+            Constraint::Length(12),
+            Constraint::Fill(1),
+        ],
+    )
+    .header(
+        Row::new(["IRQ", "Handler", "Affinity", "Rate/s", "Total", "Per CPU"]).style(header_style),
+    )
+    .block(block(irq_title));
+    frame.render_widget(irq_table, irq_area);
+
+    let queue_dt = match (&app.net_queues, &app.prev_net_queues) {
+        (Some((now, name, _)), Some((then, prev_name, _))) if now > then && name == prev_name => {
+            Some(now - then)
+        }
+        _ => None,
+    };
+    let prev_queues: HashMap<&str, &NetQueue> = app
+        .prev_net_queues
+        .as_ref()
+        .map(|(_, _, q)| q.iter().map(|q| (q.name.as_str(), q)).collect())
+        .unwrap_or_default();
+    let queue_rows: Vec<Row> = queues
+        .iter()
+        .map(|q| {
+            let before = prev_queues.get(q.name.as_str());
+            let rate = |get: fn(&QueueCounters) -> Option<u64>| {
+                let now = get(&q.counters)?;
+                let then = get(&before?.counters)?;
+                Some(now.saturating_sub(then) as f64 / queue_dt?)
+            };
+            let cpus = match &q.cpus {
+                Some(cpus) if cpus.is_empty() => "off".to_string(),
+                Some(cpus) => format_cpu_list(cpus),
+                None => "-".to_string(),
+            };
+            let extra = if q.name.starts_with("rx-") {
+                q.rps_flow_cnt
+                    .filter(|n| *n > 0)
+                    .map(|n| format!("flows {}", n))
+            } else {
+                q.bql_inflight
+                    .zip(q.bql_limit)
+                    .map(|(inflight, limit)| format!("bql {}/{}", inflight, limit))
+            };
+            let extra = [extra, q.tx_maxrate.map(|r| format!("max {}Mbit", r))]
+                .into_iter()
+                .flatten()
+                .collect::<Vec<_>>()
+                .join(" ");
+            let opt = |v: Option<String>| v.unwrap_or("-".to_string());
+            let drops = q.counters.drops.unwrap_or(0);
+            let drop_style = if rate(|c| c.drops).is_some_and(|r| r > 0.0) {
+                Style::default()
+                    .fg(app.current_theme.info_area_color.changed)
+                    .add_modifier(Modifier::BOLD)
+            } else {
+                Style::default().fg(colors.inode_val)
+            };
+
+            Row::new(vec![
+                Cell::from(Span::styled(
+                    q.name.clone(),
+                    Style::default().fg(colors.local_addr_val),
+                )),
+                Cell::from(Span::styled(cpus, Style::default().fg(colors.hostname_val))),
+                Cell::from(Span::styled(extra, Style::default().fg(colors.inode_val))),
+                Cell::from(Span::styled(
+                    opt(rate(|c| c.packets).map(|r| format!("{:.0}", r))),
+                    Style::default().fg(colors.remote_addr_val),
+                )),
+                Cell::from(Span::styled(
+                    opt(rate(|c| c.bytes).map(|r| {
+                        let (value, unit) = human_speed(r);
+                        format!("{:.1} {}", value, unit)
+                    })),
+                    Style::default().fg(colors.remote_addr_val),
+                )),
+                Cell::from(Span::styled(
+                    opt(q.counters.packets.map(|p| p.to_string())),
+                    Style::default().fg(colors.inode_val),
+                )),
+                Cell::from(Span::styled(
+                    opt(q.counters.bytes.map(|b| format_bytes(b, &app.byte_unit))),
+                    Style::default().fg(colors.inode_val),
+                )),
+                Cell::from(Span::styled(
+                    q.counters
+                        .drops
+                        .map_or("-".to_string(), |_| drops.to_string()),
+                    drop_style,
+                )),
+            ])
+        })
+        .collect();
+    let queue_table = Table::new(
+        queue_rows,
+        [
+            Constraint::Length(8),
+            Constraint::Length(12),
+            Constraint::Percentage(20),
+            Constraint::Length(10),
+            Constraint::Length(12),
+            Constraint::Length(12),
+            Constraint::Length(12),
+            Constraint::Fill(1),
+        ],
+    )
+    .header(
+        Row::new([
+            "Queue",
+            "RPS/XPS",
+            "Limits",
+            "Packets/s",
+            "Bytes/s",
+            "Packets",
+            "Bytes",
+            "Drops",
+        ])
+        .style(header_style),
+    )
+    .block(block(format!(" Queues ({}) ", queues.len())));
+    frame.render_widget(queue_table, queue_area);
+}
+
+// Kernel protocol counters, `netstat -s` style. Values that moved since the previous tick are
+// highlighted and show their per-second rate.
+fn draw_protocol_panel(app: &App, frame: &mut Frame, area: Rect) {
+    let theme = &app.current_theme.info_area_color;
+    let Some((now, stats)) = &app.proto_stats else {
+        return;
+    };
+    let prev = app.prev_proto_stats.as_ref();
+    let dt = prev.map(|(t, _)| now - t).filter(|dt| *dt > 0.0);
+
+    let rate = |cur: u64, prev: u64| match dt {
+        Some(dt) => cur.saturating_sub(prev) as f64 / dt,
+        None => 0.0,
+    };
+
+    let section = |title: &str,
+                   fields: Vec<(&'static str, u64)>,
+                   prev_fields: Option<Vec<(&'static str, u64)>>| {
+        let mut lines = vec![Line::from(Span::styled(
+            format!(" {}", title),
+            Style::default()
+                .fg(theme.heading)
+                .add_modifier(Modifier::BOLD),
+        ))];
+        for (i, (name, value)) in fields.iter().enumerate() {
+            let before = prev_fields.as_ref().map(|p| p[i].1).unwrap_or(*value);
+            let mut spans = vec![
+                Span::styled(format!("   {:<20}", name), Style::default().fg(theme.key)),
+                Span::styled(
+                    format!("{:>12}", value),
+                    if *value != before {
+                        Style::default()
+                            .fg(theme.changed)
+                            .add_modifier(Modifier::BOLD)
+                    } else {
+                        Style::default().fg(theme.val)
+                    },
+                ),
+            ];
+            // CurrEstab is a gauge: it shows how far it moved, which can be down, not a rate.
+            if *value != before {
+                let change = if *name == "CurrEstab" {
+                    format!(" {:+}", *value as i64 - before as i64)
+                } else {
+                    format!(" {:+.1}/s", rate(*value, before))
+                };
+                spans.push(Span::styled(change, Style::default().fg(theme.changed)));
+            }
+            lines.push(Line::from(spans));
+        }
+        lines.push(Line::from(""));
+        lines
+    };
+
+    let p = prev.map(|(_, p)| p);
+    let key_rates = [
+        (
+            "Retrans segs",
+            stats.tcp.retrans_segs,
+            p.map(|p| p.tcp.retrans_segs),
+        ),
+        (
+            "Listen overflows",
+            stats.tcp_ext.listen_overflows,
+            p.map(|p| p.tcp_ext.listen_overflows),
+        ),
+        (
+            "SYN cookies sent",
+            stats.tcp_ext.syncookies_sent,
+            p.map(|p| p.tcp_ext.syncookies_sent),
+        ),
+        (
+            "UDP rcvbuf errors",
+            stats.udp.rcvbuf_errors,
+            p.map(|p| p.udp.rcvbuf_errors),
+        ),
+        (
+            "IP reasm fails",
+            stats.ip.reasm_fails,
+            p.map(|p| p.ip.reasm_fails),
+        ),
+    ];
 
-    if app.show_help {
-        render_help_popup(frame, &app);
-    }
-    if app.enter_tick_active {
-        draw_tick_mode(frame, app);
-    }
-    if app.edit_rx_mode || app.edit_tx_mode {
-        draw_speed_edit_popup(frame, app);
+    let mut first = vec![Line::from(Span::styled(
+        " Rates",
+        Style::default()
+            .fg(theme.heading)
+            .add_modifier(Modifier::BOLD),
+    ))];
+    for (name, value, before) in key_rates {
+        let per_sec = rate(value, before.unwrap_or(value));
+        let style = if per_sec > 0.0 {
+            Style::default()
+                .fg(theme.changed)
+                .add_modifier(Modifier::BOLD)
+        } else {
+            Style::default().fg(theme.val)
+        };
+        first.push(Line::from(vec![
+            Span::styled(format!("   {:<20}", name), Style::default().fg(theme.key)),
+            Span::styled(format!("{:>10.1}/s", per_sec), style),
+        ]));
     }
-    if app.change_theme {
-        theme_selection_popup(frame, app);
+    first.push(Line::from(""));
+    first.extend(section(
+        "Udp",
+        stats.udp.fields(),
+        p.map(|p| p.udp.fields()),
+    ));
+
+    let mut second = section("Ip", stats.ip.fields(), p.map(|p| p.ip.fields()));
+    second.extend(section(
+        "IpExt",
+        stats.ip_ext.fields(),
+        p.map(|p| p.ip_ext.fields()),
+    ));
+    let mut third = section("Tcp", stats.tcp.fields(), p.map(|p| p.tcp.fields()));
+    third.extend(section(
+        "Icmp",
+        stats.icmp.fields(),
+        p.map(|p| p.icmp.fields()),
+    ));
+    let fourth = section(
+        "TcpExt",
+        stats.tcp_ext.fields(),
+        p.map(|p| p.tcp_ext.fields()),
+    );
+
+    let block = Block::bordered()
+        .border_type(BorderType::Plain)
+        .title(Line::from(vec![
+            Span::styled(
+                " [p] ",
+                Style::default()
+                    .fg(theme.heading)
+                    .add_modifier(Modifier::BOLD),
+            ),
+            Span::raw(format!("{} ", app.panel.title())),
+        ]))
+        .border_style(Style::default().fg(theme.heading));
+    let inner = block.inner(area);
+    frame.render_widget(block, area);
+
+    let columns = Layout::horizontal([Constraint::Ratio(1, 4); 4]).split(inner);
+    for (lines, column) in [first, second, third, fourth]
+        .into_iter()
+        .zip(columns.iter())
+    {
+        frame.render_widget(Paragraph::new(lines), *column);
     }
 }
 
@@ -1533,6 +3903,16 @@ fn render_help_popup(frame: &mut Frame, app: &App) {
             Span::styled("   K         ", Style::default().fg(theme.key)),
             Span::raw("Change tick rate (refresh interval)"),
         ]),
+        Line::from(vec![
+            Span::styled("   n         ", Style::default().fg(theme.key)),
+            Span::raw("Switch network namespace"),
+        ]),
+        Line::from(vec![
+            Span::styled("   p         ", Style::default().fg(theme.key)),
+            Span::raw(
+                "Cycle bottom panel (sockets, processes, recent, protocols, routes, neighbors, conntrack, softnet, IRQs)",
+            ),
+        ]),
         Line::from(""),
         Line::from(vec![Span::styled(
             " INTERFACES",
@@ -1550,7 +3930,11 @@ fn render_help_popup(frame: &mut Frame, app: &App) {
         ]),
         Line::from(vec![
             Span::styled("   f         ", Style::default().fg(theme.key)),
-            Span::raw("Filter interfaces by name"),
+            Span::raw("Filter interfaces by name or container"),
+        ]),
+        Line::from(vec![
+            Span::styled("   Space     ", Style::default().fg(theme.key)),
+            Span::raw("Collapse / expand a bridge, bond or VLAN parent"),
         ]),
         Line::from(vec![
             Span::styled("   R/T       ", Style::default().fg(theme.key)),
@@ -1579,6 +3963,18 @@ fn render_help_popup(frame: &mut Frame, app: &App) {
             Span::styled("   f         ", Style::default().fg(theme.key)),
             Span::raw("Filter connections (all fields)"),
         ]),
+        Line::from(vec![
+            Span::styled("   u         ", Style::default().fg(theme.key)),
+            Span::raw("Cycle TCP / UDP / TCP + UDP sockets"),
+        ]),
+        Line::from(vec![
+            Span::styled("   s         ", Style::default().fg(theme.key)),
+            Span::raw("Toggle top flows / cycle conntrack sort"),
+        ]),
+        Line::from(vec![
+            Span::styled("   Enter     ", Style::default().fg(theme.key)),
+            Span::raw("Show the sockets of the selected process (processes panel)"),
+        ]),
         Line::from(""),
         Line::from(vec![Span::styled(
             " FILTER MODE",
@@ -1593,7 +3989,7 @@ fn render_help_popup(frame: &mut Frame, app: &App) {
         Line::from(vec![
             Span::styled("             ", Style::default().fg(Color::DarkGray)),
             Span::styled(
-                "(IP, hostname, state, UID, inode)",
+                "(IP, hostname, state, protocol, process, UID, inode)",
                 Style::default().fg(Color::DarkGray),
             ),
         ]),
@@ -1612,6 +4008,10 @@ fn render_help_popup(frame: &mut Frame, app: &App) {
                 Style::default().fg(Color::DarkGray),
             ),
         ]),
+        Line::from(vec![
+            Span::styled("   g         ", Style::default().fg(theme.key)),
+            Span::raw("Jump to the locked connection's egress interface"),
+        ]),
         Line::from(vec![
             Span::styled("   Esc       ", Style::default().fg(theme.key)),
             Span::raw("Exit filter mode"),
@@ -1679,14 +4079,12 @@ fn make_bar(percent: f64, width: usize) -> String {
     format!("{}{}", "█".repeat(filled), "░".repeat(empty))
 }
 
-fn resolve_hostname(ip: &[u8; 4]) -> String {
-    if ip == &[0, 0, 0, 0] || ip == &[127, 0, 0, 1] {
+fn resolve_hostname(ip: &IpAddr) -> String {
+    if is_local_ip(ip) {
         return "-".to_string();
     }
 
-    let ip_addr = IpAddr::V4(Ipv4Addr::new(ip[0], ip[1], ip[2], ip[3]));
-
-    match dns_lookup::lookup_addr(&ip_addr) {
+    match dns_lookup::lookup_addr(ip) {
         std::result::Result::Ok(hostname) => {
             if hostname.len() > 30 {
                 format!("{}...", &hostname[..27])
@@ -1702,35 +4100,210 @@ fn interface_vec_items<'a>(
     filtered_interfaces: Option<&VecDeque<(usize, &String)>>,
     app: &App,
     interface_names: &VecDeque<String>,
+    data: &[NetworkStats],
 ) -> Vec<ListItem<'a>> {
-    if let Some(filtered) = filtered_interfaces {
-        filtered
+    let rows: HashMap<String, InterfaceRow> = app
+        .interface_rows()
+        .into_iter()
+        .map(|r| (r.name.clone(), r))
+        .collect();
+
+    let item = |name: &String| {
+        if app.gone_interfaces.contains_key(name) {
+            return ListItem::new(vec![Line::from(vec![
+                Span::raw("  "),
+                Span::styled(
+                    format!("{:<16}", name),
+                    Style::default().fg(app.current_theme.tcpconn_area_color.state_time_wait),
+                ),
+                Span::styled(
+                    "gone",
+                    Style::default().fg(app.current_theme.tcpconn_area_color.state_close_wait),
+                ),
+            ])]);
+        }
+
+        // Operstate dot; only rtnetlink reports it, so procfs-backed rows stay blank.
+        let dot = match data
             .iter()
-            .map(|(_, name)| {
-                ListItem::new(vec![Line::from(vec![
-                    Span::raw(" "),
-                    Span::styled(
-                        format!("{:<16}", name),
-                        Style::default().fg(app.current_theme.interface_area_color.data),
-                    ),
-                ])])
-            })
-            .collect()
+            .find(|i| &i.name == name)
+            .and_then(|i| i.link.as_ref())
+        {
+            Some(link) => Span::styled(
+                "●",
+                Style::default().fg(operstate_color(app, link.operstate)),
+            ),
+            None => Span::raw(" "),
+        };
+        let (depth, children) = rows.get(name).map_or((0, 0), |r| (r.depth, r.children));
+        let indent = match depth {
+            0 => String::new(),
+            d => format!("{}└ ", "  ".repeat(d - 1)),
+        };
+        let dim = Style::default().fg(app.current_theme.info_area_color.key);
+
+        let mut spans = vec![
+            dot,
+            Span::raw(" "),
+            Span::styled(indent.clone(), dim),
+            Span::styled(
+                name.clone(),
+                Style::default().fg(app.current_theme.interface_area_color.data),
+            ),
+        ];
+        if children > 0 {
+            let marker = if app.collapsed.contains(name) {
+                format!(" ▸{}", children)
+            } else {
+                " ▾".to_string()
+            };
+            spans.push(Span::styled(marker, dim));
+        }
+        // veths are annotated with whatever sits on the other end; ratatui clips long labels.
+        if let Some(peer) = app.veth_peers.get(name) {
+            spans.push(Span::styled(format!(" {}", peer.label()), dim));
+        }
+        if children == 0 {
+            return ListItem::new(vec![Line::from(spans)]);
+        }
+
+        // Parents get a second line with their members' combined traffic.
+        let (rx, tx) = app.children_rate(name);
+        let short = |bps: f64| {
+            // "KB/s" -> "K", but plain bytes keep their "B".
+            let (value, unit) = human_speed(bps);
+            let unit = unit.trim_end_matches("/s");
+            let unit = unit
+                .strip_suffix('B')
+                .filter(|u| !u.is_empty())
+                .unwrap_or(unit);
+            format!("{:.1}{}", value, unit)
+        };
+        ListItem::new(vec![
+            Line::from(spans),
+            Line::from(Span::styled(
+                format!(
+                    "  {}Σ ↓{} ↑{}",
+                    " ".repeat(indent.chars().count()),
+                    short(rx),
+                    short(tx)
+                ),
+                dim,
+            )),
+        ])
+    };
+
+    if let Some(filtered) = filtered_interfaces {
+        filtered.iter().map(|(_, name)| item(name)).collect()
     } else {
-        interface_names
-            .iter()
-            .enumerate()
-            .map(|(_, name)| {
-                ListItem::new(vec![Line::from(vec![
-                    Span::raw(" "),
-                    Span::styled(
-                        format!("{:<16}", name),
-                        Style::default().fg(app.current_theme.interface_area_color.data),
-                    ),
-                ])])
-            })
-            .collect()
+        interface_names.iter().map(item).collect()
+    }
+}
+
+// e.g. "detected, full duplex", "detected, wireless" or "configured".
+fn link_speed_source(app: &App, interface: &str) -> String {
+    let (_, source) = app.link_speed(interface);
+    let detected = app.detected_speeds.get(interface);
+    match (source, detected) {
+        (SpeedSource::Detected, Some(speed)) if speed.wireless => {
+            format!("{}, wireless", source.name())
+        }
+        (
+            SpeedSource::Detected,
+            Some(LinkSpeed {
+                duplex: Some(duplex),
+                ..
+            }),
+        ) => format!("{}, {} duplex", source.name(), duplex),
+        _ => source.name().to_string(),
+    }
+}
+
+fn operstate_color(app: &App, state: u8) -> Color {
+    let colors = &app.current_theme.tcpconn_area_color;
+    match operstate_name(state) {
+        "up" => colors.state_established,
+        "down" | "lowerlayerdown" | "notpresent" => colors.state_close_wait,
+        _ => colors.state_time_wait,
+    }
+}
+
+fn netns_selection_popup(frame: &mut Frame, app: &App) {
+    let Mode::SelectingNetns { filter, index } = &app.mode else {
+        return;
+    };
+    let area = frame.area();
+    let popup_area = Layout::vertical([
+        Constraint::Percentage(25),
+        Constraint::Percentage(50),
+        Constraint::Percentage(25),
+    ])
+    .split(area)[1];
+    let popup_area = Layout::horizontal([
+        Constraint::Percentage(25),
+        Constraint::Percentage(50),
+        Constraint::Percentage(25),
+    ])
+    .split(popup_area)[1];
+
+    let color = Color::Magenta;
+    let chunks = Layout::vertical([Constraint::Length(3), Constraint::Min(1)]).split(popup_area);
+
+    let filter_block = Paragraph::new(filter.as_str())
+        .block(
+            Block::bordered()
+                .title("Filter")
+                .border_style(Style::default().fg(color)),
+        )
+        .style(Style::default().fg(Color::White));
+
+    frame.render_widget(Clear, popup_area);
+    frame.render_widget(filter_block, chunks[0]);
+
+    let filtered = filter_netns(&app.netns_list, filter);
+    let list_items: Vec<ListItem> = filtered
+        .iter()
+        .map(|ns| {
+            let current = if ns.inode == app.netns.inode {
+                "*"
+            } else {
+                " "
+            };
+            ListItem::new(Line::from(vec![
+                Span::styled(current, Style::default().fg(color)),
+                Span::styled(
+                    format!("{:<32}", ns.name),
+                    Style::default().fg(Color::White),
+                ),
+                Span::styled(
+                    format!("net:[{}]  {} procs", ns.inode, ns.processes),
+                    Style::default().fg(Color::Gray),
+                ),
+            ]))
+        })
+        .collect();
+
+    let mut state = ListState::default();
+    state.select(Some((*index).min(filtered.len().saturating_sub(1))));
+
+    let mut block = Block::bordered()
+        .border_style(Style::default().fg(color))
+        .title("Network Namespaces")
+        .title_style(Style::default().fg(color).add_modifier(Modifier::BOLD));
+    if let Some(error) = &app.netns_error {
+        block = block.title_bottom(Line::from(Span::styled(
+            format!(" {} ", error),
+            Style::default().fg(Color::Red),
+        )));
     }
+
+    let list = List::new(list_items)
+        .block(block)
+        .highlight_style(Style::default().bg(Color::Red).add_modifier(Modifier::BOLD))
+        .highlight_symbol("> ")
+        .highlight_spacing(HighlightSpacing::Always);
+
+    frame.render_stateful_widget(list, chunks[1], &mut state);
 }
 
 fn theme_selection_popup(frame: &mut Frame, app: &mut App) {
@@ -1752,85 +4325,119 @@ fn theme_selection_popup(frame: &mut Frame, app: &mut App) {
 
     let (title, color) = ("Themes", Color::Magenta);
 
-    match &app.mode {
-        Mode::SelectingTheme { filter, index } => {
-            let chunks =
-                Layout::vertical([Constraint::Length(3), Constraint::Min(1)]).split(popup_area);
+    if let Mode::SelectingTheme { filter, index } = &app.mode {
+        let chunks =
+            Layout::vertical([Constraint::Length(3), Constraint::Min(1)]).split(popup_area);
 
-            let filter_block = Paragraph::new(filter.as_str())
-                .block(
-                    Block::bordered()
-                        .title("Filter")
-                        .border_style(Style::default().fg(Color::Magenta)),
-                )
-                .style(Style::default().fg(Color::White));
+        let filter_block = Paragraph::new(filter.as_str())
+            .block(
+                Block::bordered()
+                    .title("Filter")
+                    .border_style(Style::default().fg(Color::Magenta)),
+            )
+            .style(Style::default().fg(Color::White));
 
-            frame.render_widget(Clear, popup_area);
-            frame.render_widget(filter_block, chunks[0]);
+        frame.render_widget(Clear, popup_area);
+        frame.render_widget(filter_block, chunks[0]);
 
-            let filtered: Vec<(usize, &str)> = THEMES
-                .iter()
-                .enumerate()
-                .filter(|(_, (name, _))| name.to_lowercase().contains(&filter.to_lowercase()))
-                .map(|(i, (name, _))| (i, *name))
-                .collect();
+        let filtered: Vec<(usize, &str)> = THEMES
+            .iter()
+            .enumerate()
+            .filter(|(_, (name, _))| name.to_lowercase().contains(&filter.to_lowercase()))
+            .map(|(i, (name, _))| (i, *name))
+            .collect();
 
-            let list_items: Vec<ListItem> = filtered
-                .iter()
-                .map(|(_, name)| {
-                    ListItem::new(Line::from(vec![
-                        Span::raw(" "),
-                        Span::styled(format!("{:<15}", name), Style::default().fg(Color::White)),
-                    ]))
-                })
-                .collect();
+        let list_items: Vec<ListItem> = filtered
+            .iter()
+            .map(|(_, name)| {
+                ListItem::new(Line::from(vec![
+                    Span::raw(" "),
+                    Span::styled(format!("{:<15}", name), Style::default().fg(Color::White)),
+                ]))
+            })
+            .collect();
 
-            let mut state = ListState::default();
-            let max_index = filtered.len().saturating_sub(1);
-            let sel = (*index).min(max_index);
-            state.select(Some(sel));
+        let mut state = ListState::default();
+        let max_index = filtered.len().saturating_sub(1);
+        let sel = (*index).min(max_index);
+        state.select(Some(sel));
 
-            let list = List::new(list_items)
-                .block(
-                    Block::bordered()
-                        .border_style(Style::default().fg(color))
-                        .title(title)
-                        .title_style(Style::default().fg(color).add_modifier(Modifier::BOLD)),
-                )
-                .highlight_style(Style::default().bg(Color::Red).add_modifier(Modifier::BOLD))
-                .highlight_symbol("> ")
-                .highlight_spacing(HighlightSpacing::Always);
+        let list = List::new(list_items)
+            .block(
+                Block::bordered()
+                    .border_style(Style::default().fg(color))
+                    .title(title)
+                    .title_style(Style::default().fg(color).add_modifier(Modifier::BOLD)),
+            )
+            .highlight_style(Style::default().bg(Color::Red).add_modifier(Modifier::BOLD))
+            .highlight_symbol("> ")
+            .highlight_spacing(HighlightSpacing::Always);
 
-            frame.render_stateful_widget(list, chunks[1], &mut state);
-        }
-        _ => {}
+        frame.render_stateful_widget(list, chunks[1], &mut state);
     }
 }
 
-fn build_tcp_table<'a>(visible_tcp_rows: Vec<Row<'a>>) -> Table<'a> {
-    Table::new(
-        visible_tcp_rows,
-        [
-            Constraint::Percentage(20),
-            Constraint::Percentage(20),
-            Constraint::Percentage(30),
+fn build_socket_table<'a>(
+    visible_tcp_rows: Vec<Row<'a>>,
+    columns: SocketColumns,
+    sort: SocketSort,
+) -> Table<'a> {
+    let mut widths = Vec::new();
+    let mut header = Vec::new();
+    if columns.proto {
+        widths.push(Constraint::Length(5));
+        header.push(Cell::from("Proto"));
+    }
+    widths.extend([
+        Constraint::Percentage(18),
+        Constraint::Percentage(18),
+        Constraint::Percentage(16),
+        Constraint::Length(12),
+        Constraint::Length(10),
+        Constraint::Length(10),
+    ]);
+    header.extend([
+        Cell::from("Local Address"),
+        Cell::from("Remote Address"),
+        Cell::from("Hostname"),
+        Cell::from("State"),
+        Cell::from("TX:RX"),
+        Cell::from("Egress"),
+    ]);
+    if columns.drops {
+        widths.push(Constraint::Length(7));
+        header.push(Cell::from("Drops"));
+    }
+    if columns.tcp_info {
+        let marker = if sort == SocketSort::TopFlows {
+            " ▾"
+        } else {
+            ""
+        };
+        widths.extend([
             Constraint::Length(12),
-            Constraint::Length(10),
-            Constraint::Length(6),
-            Constraint::Fill(1),
-        ],
-    )
-    .header(
-        Row::new(vec![
-            Cell::from("Local Address"),
-            Cell::from("Remote Address"),
-            Cell::from("Hostname"),
-            Cell::from("State"),
-            Cell::from("TX:RX"),
-            Cell::from("UID"),
-            Cell::from("Inode"),
-        ])
-        .style(
+            Constraint::Length(12),
+            Constraint::Length(13),
+        ]);
+        header.extend([
+            Cell::from(format!("RX/s{}", marker)),
+            Cell::from(format!("TX/s{}", marker)),
+            Cell::from("RTT/Cwnd"),
+        ]);
+    }
+    widths.extend([
+        Constraint::Percentage(12),
+        Constraint::Length(6),
+        Constraint::Fill(1),
+    ]);
+    header.extend([
+        Cell::from("Process"),
+        Cell::from("UID"),
+        Cell::from("Inode"),
+    ]);
+
+    Table::new(visible_tcp_rows, widths).header(
+        Row::new(header).style(
             Style::default()
                 .fg(Color::DarkGray)
                 .add_modifier(Modifier::BOLD),
@@ -1838,13 +4445,22 @@ fn build_tcp_table<'a>(visible_tcp_rows: Vec<Row<'a>>) -> Table<'a> {
     )
 }
 
-fn return_summary_lines<'a>(
-    app: &App,
-    tcp_data_len: usize,
-    active: usize,
-    unique_ips: HashSet<[u8; 4]>,
-    local_only: usize,
-) -> Vec<Line<'a>> {
+fn return_summary_lines<'a>(app: &App, sockets: &[&dyn Socket]) -> Vec<Line<'a>> {
+    let tcp_data_len = sockets.len();
+    let unique_ips: HashSet<IpAddr> = sockets
+        .iter()
+        .filter(|c| !is_local_ip(&c.remote_ip()))
+        .map(|c| c.remote_ip())
+        .collect();
+    let active = sockets
+        .iter()
+        .filter(|c| c.tx_queue() > 0 || c.rx_queue() > 0)
+        .count();
+    let local_only = sockets
+        .iter()
+        .filter(|c| is_local_ip(&c.remote_ip()))
+        .count();
+
     let mut summary_lines = vec![Line::from(vec![
         Span::styled(
             " Total       : ",
@@ -1893,28 +4509,195 @@ fn return_summary_lines<'a>(
         ),
     ]));
 
+    summary_lines.push(Line::from(""));
+
+    for protocol in [Protocol::Tcp, Protocol::Udp] {
+        let count = sockets.iter().filter(|c| c.protocol() == protocol).count();
+        let dropped: u64 = sockets
+            .iter()
+            .filter(|c| c.protocol() == protocol)
+            .filter_map(|c| c.drops())
+            .sum();
+        if count == 0 {
+            continue;
+        }
+
+        let mut spans = vec![
+            Span::styled(
+                format!(" {:<12}: ", protocol.name()),
+                Style::default().fg(app.current_theme.tcpinfo_area_color.key),
+            ),
+            Span::styled(
+                format!("{}", count),
+                Style::default().fg(app.current_theme.tcpinfo_area_color.val),
+            ),
+        ];
+        if dropped > 0 {
+            spans.push(Span::styled(
+                format!(" ({} drops)", dropped),
+                Style::default().fg(app.current_theme.tcpinfo_area_color.val),
+            ));
+        }
+        summary_lines.push(Line::from(spans));
+    }
+
+    let tcp_infos: Vec<&TcpInfo> = sockets.iter().filter_map(|c| c.tcp_info()).collect();
+    if !tcp_infos.is_empty() {
+        let retrans: u64 = tcp_infos.iter().map(|i| i.total_retrans as u64).sum();
+        let acked: u64 = tcp_infos.iter().map(|i| i.bytes_acked).sum();
+        let received: u64 = tcp_infos.iter().map(|i| i.bytes_received).sum();
+        let rtts: Vec<u64> = tcp_infos
+            .iter()
+            .filter(|i| i.rtt_us > 0)
+            .map(|i| i.rtt_us as u64)
+            .collect();
+        let avg_rtt = if rtts.is_empty() {
+            "-".to_string()
+        } else {
+            format_rtt((rtts.iter().sum::<u64>() / rtts.len() as u64) as u32)
+        };
+
+        for (key, val) in [
+            (" Avg RTT     : ", avg_rtt),
+            (" Retrans     : ", retrans.to_string()),
+            (" Acked       : ", format_bytes(acked, &app.byte_unit)),
+            (" Received    : ", format_bytes(received, &app.byte_unit)),
+        ] {
+            summary_lines.push(Line::from(vec![
+                Span::styled(
+                    key,
+                    Style::default().fg(app.current_theme.tcpinfo_area_color.key),
+                ),
+                Span::styled(
+                    val,
+                    Style::default().fg(app.current_theme.tcpinfo_area_color.val),
+                ),
+            ]));
+        }
+    }
+
+    summary_lines.push(Line::from(vec![
+        Span::styled(
+            " Source      : ",
+            Style::default().fg(app.current_theme.tcpinfo_area_color.key),
+        ),
+        Span::styled(
+            app.socket_backend.name(),
+            Style::default().fg(app.current_theme.tcpinfo_area_color.val),
+        ),
+    ]));
+
+    // Kernel-wide figures, independent of the current view and filter.
+    if let Some(stat) = &app.sockstat {
+        let page = page_size();
+        let tcp_mem = format_bytes(stat.tcp_mem * page, &app.byte_unit);
+        let (tcp_mem, under_pressure) = match stat.tcp_mem_usage() {
+            Some((percent, pressure)) => (format!("{} {:.1}%", tcp_mem, percent), pressure),
+            None => (tcp_mem, false),
+        };
+
+        summary_lines.push(Line::from(""));
+        for (key, val) in [
+            (
+                " TCP in/alloc: ",
+                format!("{}/{}", stat.tcp_inuse, stat.tcp_alloc),
+            ),
+            (
+                " Orphan / TW : ",
+                format!("{} / {}", stat.tcp_orphan, stat.tcp_tw),
+            ),
+            (" TCP mem     : ", tcp_mem),
+            (
+                " UDP in/mem  : ",
+                format!(
+                    "{} / {}",
+                    stat.udp_inuse,
+                    format_bytes(stat.udp_mem * page, &app.byte_unit)
+                ),
+            ),
+            (
+                " RAW / FRAG  : ",
+                format!("{} / {}", stat.raw_inuse, stat.frag_inuse),
+            ),
+            (
+                " FRAG mem    : ",
+                format_bytes(stat.frag_memory, &app.byte_unit),
+            ),
+        ] {
+            let style = if key == " TCP mem     : " && under_pressure {
+                Style::default()
+                    .fg(app.current_theme.info_area_color.changed)
+                    .add_modifier(Modifier::BOLD)
+            } else {
+                Style::default().fg(app.current_theme.tcpinfo_area_color.val)
+            };
+            summary_lines.push(Line::from(vec![
+                Span::styled(
+                    key,
+                    Style::default().fg(app.current_theme.tcpinfo_area_color.key),
+                ),
+                Span::styled(val, style),
+            ]));
+        }
+    }
+
     summary_lines.push(Line::from(""));
     summary_lines
 }
 
-fn build_tcp_row<'a>(
-    conn: &TcpStats,
+// Connections that closed moments ago in the current view, with the process that owned them. The
+// sockets table keeps them on top, greyed out, for a few ticks.
+fn just_closed_sockets(app: &App, view: SocketView) -> Vec<(&dyn Socket, String)> {
+    app.just_closed()
+        .map(|c| {
+            (
+                c.last.socket(),
+                c.process.clone().unwrap_or("-".to_string()),
+            )
+        })
+        .filter(|(conn, _)| match view {
+            SocketView::Tcp => conn.protocol() == Protocol::Tcp,
+            SocketView::Udp => conn.protocol() == Protocol::Udp,
+            SocketView::All => true,
+        })
+        .collect()
+}
+
+fn build_socket_row<'a>(
+    app: &App,
+    conn: &dyn Socket,
     hostname: String,
+    process: String,
     is_selected: bool,
-    theme: &TcpConnAreaColor,
+    closed: bool,
+    columns: SocketColumns,
 ) -> Row<'a> {
-    let local_addr = format!("{}:{}", format_ip(&conn.local_ip), conn.local_port);
-    let remote_addr = format!("{}:{}", format_ip(&conn.remote_ip), conn.remote_port);
-    let state = tcp_state_name(conn.state);
+    let theme = &app.current_theme.tcpconn_area_color;
+    let egress = app.egress_interface(conn);
+    let rate = app.flow_rates.get(&conn.socket_key());
+    let local_addr = format_ip_port(&conn.local_ip(), conn.local_port());
+    let remote_addr = format_ip_port(&conn.remote_ip(), conn.remote_port());
+    let state = if closed { "closed" } else { conn.state_name() };
+    let (tx_queue, rx_queue) = (conn.tx_queue(), conn.rx_queue());
+
+    // The locked row is drawn in white, and greyed out once its socket has closed. A connection
+    // that just opened stands out for a few ticks.
+    let fixed_color = if closed {
+        Some(Color::DarkGray)
+    } else if is_selected {
+        Some(Color::White)
+    } else if app.is_new_connection(conn) {
+        Some(app.current_theme.info_area_color.changed)
+    } else {
+        None
+    };
 
-    let state_style = if is_selected {
-        Style::default()
-            .fg(Color::White)
-            .add_modifier(Modifier::BOLD)
+    let state_style = if let Some(color) = fixed_color {
+        Style::default().fg(color).add_modifier(Modifier::BOLD)
     } else {
         match state {
             "ESTABLISHED" => Style::default().fg(theme.state_established),
-            "LISTEN" => Style::default().fg(theme.state_listen),
+            "LISTEN" | "UNCONN" => Style::default().fg(theme.state_listen),
             "TIME_WAIT" => Style::default().fg(theme.state_time_wait),
             "CLOSE_WAIT" => Style::default().fg(theme.state_close_wait),
             "SYN_SENT" | "SYN_RECV" => Style::default()
@@ -1925,9 +4708,9 @@ fn build_tcp_row<'a>(
         }
     };
 
-    let queue_style = if is_selected {
-        Style::default().fg(Color::White)
-    } else if conn.tx_queue > 0 || conn.rx_queue > 0 {
+    let queue_style = if let Some(color) = fixed_color {
+        Style::default().fg(color)
+    } else if tx_queue > 0 || rx_queue > 0 {
         Style::default()
             .fg(theme.txrx_val)
             .add_modifier(Modifier::BOLD)
@@ -1935,15 +4718,19 @@ fn build_tcp_row<'a>(
         Style::default().fg(theme.uid_val)
     };
 
+    let drops_style = if let Some(color) = fixed_color {
+        Style::default().fg(color)
+    } else if conn.drops().unwrap_or(0) > 0 {
+        Style::default()
+            .fg(theme.state_close_wait)
+            .add_modifier(Modifier::BOLD)
+    } else {
+        Style::default().fg(theme.uid_val)
+    };
+
     let (local_addr_color, remote_addr_color, hostname_color, uid_color, inode_color) =
-        if is_selected {
-            (
-                Color::White,
-                Color::White,
-                Color::White,
-                Color::White,
-                Color::White,
-            )
+        if let Some(color) = fixed_color {
+            (color, color, color, color, color)
         } else {
             (
                 theme.local_addr_val,
@@ -1954,7 +4741,24 @@ fn build_tcp_row<'a>(
             )
         };
 
-    Row::new(vec![
+    let process_style = if let Some(color) = fixed_color {
+        Style::default().fg(color)
+    } else if process == "unknown" {
+        Style::default()
+            .fg(Color::DarkGray)
+            .add_modifier(Modifier::ITALIC)
+    } else {
+        Style::default().fg(theme.hostname_val)
+    };
+
+    let mut cells = Vec::new();
+    if columns.proto {
+        cells.push(Cell::from(Span::styled(
+            conn.protocol().name(),
+            Style::default().fg(inode_color),
+        )));
+    }
+    cells.extend([
         Cell::from(Span::styled(
             local_addr,
             Style::default().fg(local_addr_color),
@@ -1966,16 +4770,56 @@ fn build_tcp_row<'a>(
         Cell::from(Span::styled(hostname, Style::default().fg(hostname_color))),
         Cell::from(Span::styled(state.to_string(), state_style)),
         Cell::from(Span::styled(
-            format!("{}:{}", conn.tx_queue, conn.rx_queue),
+            format!("{}:{}", tx_queue, rx_queue),
             queue_style,
         )),
         Cell::from(Span::styled(
-            conn.uid.to_string(),
+            egress.unwrap_or("-".to_string()),
+            Style::default().fg(hostname_color),
+        )),
+    ]);
+    if columns.drops {
+        let drops = conn
+            .drops()
+            .map(|d| d.to_string())
+            .unwrap_or("-".to_string());
+        cells.push(Cell::from(Span::styled(drops, drops_style)));
+    }
+    if columns.tcp_info {
+        for bps in [rate.map(|(rx, _)| *rx), rate.map(|(_, tx)| *tx)] {
+            let style = if let Some(color) = fixed_color {
+                Style::default().fg(color)
+            } else if bps.is_some_and(|bps| bps > 0.0) {
+                Style::default()
+                    .fg(theme.txrx_val)
+                    .add_modifier(Modifier::BOLD)
+            } else {
+                Style::default().fg(theme.uid_val)
+            };
+            let text = bps.map(format_rate).unwrap_or("-".to_string());
+            cells.push(Cell::from(Span::styled(text, style)));
+        }
+        let rtt_cwnd = conn
+            .tcp_info()
+            .filter(|info| info.rtt_us > 0)
+            .map(|info| format!("{}/{}", format_rtt(info.rtt_us), info.snd_cwnd))
+            .unwrap_or("-".to_string());
+        cells.push(Cell::from(Span::styled(
+            rtt_cwnd,
+            Style::default().fg(uid_color),
+        )));
+    }
+    cells.extend([
+        Cell::from(Span::styled(process, process_style)),
+        Cell::from(Span::styled(
+            conn.uid().to_string(),
             Style::default().fg(uid_color),
         )),
         Cell::from(Span::styled(
-            conn.inode.to_string(),
+            conn.inode().to_string(),
             Style::default().fg(inode_color),
         )),
-    ])
+    ]);
+
+    Row::new(cells)
 }